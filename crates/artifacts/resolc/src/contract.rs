@@ -1,4 +1,4 @@
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 
 use alloy_json_abi::JsonAbi;
 use foundry_compilers_artifacts_solc::{DevDoc, LosslessMetadata, StorageLayout, UserDoc};
@@ -44,6 +44,21 @@ pub struct ResolcContract {
     pub missing_libraries: Option<HashSet<String>>,
 }
 
+impl ResolcContract {
+    /// Returns the missing libraries grouped by the source file that declares them.
+    ///
+    /// `resolc` reports every missing library as a `<path>:<name>` identifier.
+    pub fn missing_libraries_by_file(&self) -> Option<BTreeMap<String, BTreeSet<String>>> {
+        let libraries = self.missing_libraries.as_ref()?;
+        let mut by_file = BTreeMap::<String, BTreeSet<String>>::new();
+        for library in libraries {
+            let (file, name) = library.rsplit_once(':').unwrap_or(("", library));
+            by_file.entry(file.to_string()).or_default().insert(name.to_string());
+        }
+        Some(by_file)
+    }
+}
+
 impl From<ResolcContract> for foundry_compilers_artifacts_solc::Contract {
     fn from(contract: ResolcContract) -> Self {
         let meta = match contract.metadata.as_ref() {
@@ -71,9 +86,13 @@ impl From<ResolcContract> for foundry_compilers_artifacts_solc::Contract {
             _ => None,
         };
 
+        let missing_libraries = contract.missing_libraries_by_file();
+        let mut evm = contract.evm;
+        let polkavm_assembly = evm.as_mut().and_then(|evm| evm.assembly_text.take());
+
         Self {
             abi: contract.abi,
-            evm: contract.evm.map(Into::into),
+            evm: evm.map(Into::into),
             metadata: meta,
             userdoc: contract.userdoc.unwrap_or_default(),
             devdoc: contract.devdoc.unwrap_or_default(),
@@ -83,6 +102,10 @@ impl From<ResolcContract> for foundry_compilers_artifacts_solc::Contract {
             ewasm: None,
             ir_optimized: contract.ir_optimized,
             ir_optimized_ast: None,
+            factory_dependencies: contract.factory_dependencies,
+            missing_libraries,
+            polkavm_source_map: None,
+            polkavm_assembly,
         }
     }
 }
@@ -174,4 +197,36 @@ mod tests {
         assert_eq!(solc_contract1.metadata.as_ref().unwrap().metadata.compiler.version, "0.8.19");
         assert_eq!(solc_contract2.metadata.as_ref().unwrap().metadata.compiler.version, "0.8.25");
     }
+
+    #[test]
+    fn conversion_keeps_factory_dependencies() {
+        let mut contract = make_contract(None);
+        contract.factory_dependencies =
+            Some(BTreeMap::from([("0x01".to_string(), "src/Child.sol:Child".to_string())]));
+        let solc_contract: foundry_compilers_artifacts_solc::Contract = contract.clone().into();
+
+        assert_eq!(solc_contract.factory_dependencies, contract.factory_dependencies);
+    }
+
+    #[test]
+    fn conversion_groups_missing_libraries_by_file() {
+        let mut contract = make_contract(None);
+        contract.missing_libraries = Some(HashSet::from([
+            "src/Math.sol:Math".to_string(),
+            "src/Math.sol:SafeMath".to_string(),
+            "src/Strings.sol:Strings".to_string(),
+        ]));
+        let solc_contract: foundry_compilers_artifacts_solc::Contract = contract.into();
+
+        assert_eq!(
+            solc_contract.missing_libraries,
+            Some(BTreeMap::from([
+                (
+                    "src/Math.sol".to_string(),
+                    BTreeSet::from(["Math".to_string(), "SafeMath".to_string()])
+                ),
+                ("src/Strings.sol".to_string(), BTreeSet::from(["Strings".to_string()])),
+            ]))
+        );
+    }
 }
@@ -3,12 +3,55 @@
 use std::{collections::BTreeMap, path::PathBuf};
 
 pub mod contract;
+pub mod libraries;
+pub mod sourcemap;
 use contract::ResolcContract;
 use foundry_compilers_artifacts_solc::{
     Bytecode, DeployedBytecode, Error, FileToContractsMap, SourceFile,
 };
 use serde::{Deserialize, Serialize};
 
+/// The magic bytes every PolkaVM blob starts with.
+pub const POLKAVM_BLOB_MAGIC: &[u8] = b"PVM\0";
+
+/// Returns `true` if the given code is a PolkaVM blob.
+pub fn is_polkavm_blob(code: &[u8]) -> bool {
+    code.starts_with(POLKAVM_BLOB_MAGIC)
+}
+
+/// The error code of the diagnostic reported for contracts that still need to be compiled with
+/// the addresses of their libraries, see [`libraries::PolkaVmLibraries`].
+///
+/// Chosen outside of the range of the 4-digit error codes used by `solc`.
+pub const MISSING_LIBRARIES_ERROR_CODE: u64 = 10000;
+
+/// The error code of the diagnostic reported for PolkaVM blobs exceeding the configured size limit.
+pub const BLOB_SIZE_ERROR_CODE: u64 = 10001;
+
+/// The error code of the diagnostic reported for PolkaVM initcode exceeding the configured size
+/// limit.
+pub const INITCODE_SIZE_ERROR_CODE: u64 = 10002;
+
+/// The error code of the diagnostic reported for PolkaVM contracts whose blob, heap and stack
+/// exceed the configured memory budget.
+pub const MEMORY_BUDGET_ERROR_CODE: u64 = 10003;
+
+/// The error codes of errors which can be ignored via the ignored error codes of a project, unlike
+/// other errors.
+///
+/// Contracts with missing libraries can be linked separately and size limits are enforced by
+/// the project itself, so their errors don't necessarily fail the build.
+pub const IGNORABLE_ERROR_CODES: &[u64] = &[
+    MISSING_LIBRARIES_ERROR_CODE,
+    BLOB_SIZE_ERROR_CODE,
+    INITCODE_SIZE_ERROR_CODE,
+    MEMORY_BUDGET_ERROR_CODE,
+];
+
+/// The error code of the warnings reported for output `resolc` or `solc` printed to `stderr`
+/// during a successful compilation.
+pub const STDERR_WARNING_ERROR_CODE: u64 = 10004;
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
 pub struct ResolcCompilerOutput {
     /// The file-contract hashmap.
@@ -77,7 +120,8 @@ impl From<ResolcEVM> for foundry_compilers_artifacts_solc::Evm {
                 immutable_references: BTreeMap::new(),
             }),
             method_identifiers: evm.method_identifiers,
-            assembly: evm.assembly_text,
+            // PolkaVM assembly is kept in `Contract::polkavm_assembly`
+            assembly: None,
             legacy_assembly: None,
             gas_estimates: None,
         }
@@ -85,3 +129,14 @@ impl From<ResolcEVM> for foundry_compilers_artifacts_solc::Evm {
 }
 
 pub type ResolcContracts = FileToContractsMap<ResolcContract>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_detect_polkavm_blob() {
+        assert!(is_polkavm_blob(b"PVM\0\x01\x02"));
+        assert!(!is_polkavm_blob(&[0x60, 0x80, 0x60, 0x40]));
+    }
+}
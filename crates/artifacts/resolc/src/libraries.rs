@@ -0,0 +1,155 @@
+//! Library linking for PolkaVM contracts.
+//!
+//! Unlike EVM bytecode, PolkaVM blobs emitted by `resolc` don't contain `__$hash$__` placeholders
+//! for unlinked libraries, so there is nothing to patch in an existing blob. Library addresses are
+//! embedded during compilation instead and every library without a known address is reported via
+//! `missingLibraries`. Linking such a contract therefore means recompiling it with the addresses
+//! passed to `resolc` via the `libraries` setting, which `Project::link_polkavm` of
+//! `foundry-compilers` does with the addresses collected here.
+
+use alloy_primitives::Address;
+use foundry_compilers_artifacts_solc::{ConfigurableContractArtifact, Contract, Libraries};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The libraries an unlinked PolkaVM contract needs, and the addresses it is linked with.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PolkaVmLibraries {
+    /// Libraries without an address yet, `(file path -> Lib names)`.
+    missing: BTreeMap<String, BTreeSet<String>>,
+    /// Libraries linked so far, `(file path -> (Lib name -> Address))`.
+    linked: BTreeMap<String, BTreeMap<String, Address>>,
+}
+
+impl PolkaVmLibraries {
+    /// Creates the libraries from the missing libraries reported by `resolc`.
+    pub fn new(missing_libraries: BTreeMap<String, BTreeSet<String>>) -> Self {
+        Self { missing: missing_libraries, linked: Default::default() }
+    }
+
+    /// Returns the missing libraries of the contract.
+    pub fn from_contract(contract: &Contract) -> Self {
+        Self::new(contract.missing_libraries.clone().unwrap_or_default())
+    }
+
+    /// Returns the missing libraries of the artifact.
+    pub fn from_artifact(artifact: &ConfigurableContractArtifact) -> Self {
+        Self::new(artifact.missing_libraries.clone().unwrap_or_default())
+    }
+
+    /// Whether there are still libraries without an address.
+    pub fn has_missing(&self) -> bool {
+        !self.missing.is_empty()
+    }
+
+    /// Returns the fully qualified names (`file.sol:Math`) of all libraries without an address.
+    pub fn missing_libraries(&self) -> impl Iterator<Item = String> + '_ {
+        self.missing
+            .iter()
+            .flat_map(|(file, libs)| libs.iter().map(move |lib| format!("{file}:{lib}")))
+    }
+
+    /// Returns whether the library is still waiting for an address, using the qualified name.
+    pub fn is_missing_fully_qualified(&self, name: &str) -> bool {
+        name.rsplit_once(':').is_some_and(|(file, lib)| self.is_missing(file, lib))
+    }
+
+    /// Returns whether the library is still waiting for an address.
+    pub fn is_missing(&self, file: &str, library: &str) -> bool {
+        self.missing.get(file).is_some_and(|libs| libs.contains(library))
+    }
+
+    /// Links a library to an address using its fully qualified name.
+    ///
+    /// The fully qualified library name is the path of its source file and the library name
+    /// separated by `:` like `file.sol:Math`
+    ///
+    /// This is a noop if the library is not needed by the contract.
+    pub fn link_fully_qualified(&mut self, name: &str, addr: Address) -> &mut Self {
+        if let Some((file, lib)) = name.rsplit_once(':') {
+            self.link(file, lib, addr);
+        }
+        self
+    }
+
+    /// Links a library to an address using the `file` and `library` names.
+    ///
+    /// See [`link_fully_qualified`](Self::link_fully_qualified).
+    pub fn link(&mut self, file: &str, library: &str, addr: Address) -> &mut Self {
+        if let Some(libs) = self.missing.get_mut(file) {
+            if libs.remove(library) {
+                self.linked.entry(file.to_string()).or_default().insert(library.to_string(), addr);
+            }
+            if libs.is_empty() {
+                self.missing.remove(file);
+            }
+        }
+        self
+    }
+
+    /// Links all provided `(file, lib, addr)`.
+    pub fn link_all<I, S, T>(&mut self, libs: I) -> &mut Self
+    where
+        I: IntoIterator<Item = (S, T, Address)>,
+        S: AsRef<str>,
+        T: AsRef<str>,
+    {
+        for (file, lib, addr) in libs.into_iter() {
+            self.link(file.as_ref(), lib.as_ref(), addr);
+        }
+        self
+    }
+
+    /// Returns the addresses of all linked libraries, to be used as the `libraries` setting when
+    /// recompiling the contract.
+    pub fn libraries(&self) -> Libraries {
+        Libraries {
+            libs: self
+                .linked
+                .iter()
+                .map(|(file, libs)| {
+                    (
+                        file.into(),
+                        libs.iter().map(|(lib, addr)| (lib.clone(), addr.to_string())).collect(),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn can_link_missing_libraries() {
+        let mut refs = PolkaVmLibraries::new(BTreeMap::from([
+            ("src/Math.sol".to_string(), BTreeSet::from(["Math".to_string(), "Trig".to_string()])),
+            ("src/Strings.sol".to_string(), BTreeSet::from(["Strings".to_string()])),
+        ]));
+        assert!(refs.has_missing());
+        assert_eq!(
+            refs.missing_libraries().collect::<Vec<_>>(),
+            ["src/Math.sol:Math", "src/Math.sol:Trig", "src/Strings.sol:Strings"]
+        );
+
+        let math = Address::with_last_byte(1);
+        let strings = Address::with_last_byte(2);
+        refs.link_fully_qualified("src/Math.sol:Math", math)
+            .link("src/Strings.sol", "Strings", strings)
+            // not needed by the contract
+            .link("src/Other.sol", "Other", strings);
+        assert!(refs.is_missing_fully_qualified("src/Math.sol:Trig"));
+        assert!(!refs.is_missing("src/Math.sol", "Math"));
+        assert!(refs.has_missing());
+
+        refs.link_all([("src/Math.sol", "Trig", math)]);
+        assert!(!refs.has_missing());
+
+        let libraries = refs.libraries();
+        assert_eq!(libraries.libs.len(), 2);
+        assert_eq!(libraries.libs[Path::new("src/Math.sol")]["Trig"], math.to_string());
+        assert!(!libraries.libs.contains_key(Path::new("src/Other.sol")));
+    }
+}
@@ -0,0 +1,205 @@
+//! Source maps for PolkaVM contracts.
+//!
+//! PolkaVM instruction offsets have nothing in common with EVM program counters, so the source
+//! maps emitted by `solc` can't be used for PolkaVM code. When compiled with debug information,
+//! `resolc` embeds line programs into the blob instead, which map instruction ranges to
+//! `file:line:column` locations. These are translated into a [`PolkaVmSourceMap`] of byte ranges
+//! in the sources, like the elements of a Solidity source map.
+
+use foundry_compilers_artifacts_solc::sourcemap::{PolkaVmSourceMap, SourceElement};
+use polkavm_common::program::{
+    InstructionSet, ProgramBlob, ProgramCounter, ProgramParseError, ISA32_V1, ISA64_V1,
+};
+use serde::{Deserialize, Serialize};
+
+/// A range of PolkaVM instructions and the source location it was compiled from, as recorded in
+/// the debug information of the blob.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolkaVmDebugLine {
+    /// The offset of the first instruction.
+    pub start: u32,
+    /// The offset after the last instruction.
+    pub end: u32,
+    /// The path of the source file, as passed to `resolc`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// The 1-based line.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+    /// The 1-based column.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column: Option<u32>,
+}
+
+/// Returns the debug line information of the PolkaVM blob, or `None` if it contains no debug
+/// information.
+///
+/// Inlined calls are attributed to the innermost location.
+pub fn polkavm_debug_lines(
+    blob: &[u8],
+) -> Result<Option<Vec<PolkaVmDebugLine>>, ProgramParseError> {
+    let blob = ProgramBlob::parse(blob.to_vec().into())?;
+    let offsets = if blob.is_64_bit() {
+        instruction_offsets(&blob, ISA64_V1)
+    } else {
+        instruction_offsets(&blob, ISA32_V1)
+    };
+
+    let mut lines = Vec::new();
+    let mut covered = 0;
+    for offset in offsets {
+        // every line program covers a whole function
+        if offset < covered {
+            continue;
+        }
+        let Some(mut program) = blob.get_debug_line_program_at(ProgramCounter(offset))? else {
+            continue;
+        };
+        while let Some(region) = program.run()? {
+            let range = region.instruction_range();
+            let frame = region.frames().last();
+            lines.push(PolkaVmDebugLine {
+                start: range.start.0,
+                end: range.end.0,
+                path: frame.as_ref().and_then(|frame| frame.path().ok()?.map(str::to_string)),
+                line: frame.as_ref().and_then(|frame| frame.line()),
+                column: frame.as_ref().and_then(|frame| frame.column()),
+            });
+            covered = covered.max(range.end.0);
+        }
+    }
+
+    Ok((!lines.is_empty()).then_some(lines))
+}
+
+/// Returns the source map of the PolkaVM blob, or `None` if it contains no debug information.
+///
+/// The source file paths of the debug information are resolved via `source`, returning the
+/// source index and the content of the file. Instructions of unknown files are mapped to elements
+/// without a source index.
+pub fn polkavm_source_map<'a>(
+    blob: &[u8],
+    source: impl Fn(&str) -> Option<(u32, &'a str)>,
+) -> Result<Option<PolkaVmSourceMap>, ProgramParseError> {
+    let Some(lines) = polkavm_debug_lines(blob)? else { return Ok(None) };
+
+    let mut map = PolkaVmSourceMap::new();
+    for line in lines {
+        let element = line
+            .path
+            .as_deref()
+            .and_then(|path| {
+                let (index, content) = source(path)?;
+                Some(source_element(content, index, line.line?, line.column))
+            })
+            .unwrap_or_default();
+        map.insert(line.start, element);
+        // overwritten by the next region if it's adjacent
+        map.insert(line.end, SourceElement::new());
+    }
+
+    Ok(Some(map))
+}
+
+fn instruction_offsets<I: InstructionSet>(blob: &ProgramBlob, instruction_set: I) -> Vec<u32> {
+    blob.instructions(instruction_set).map(|instruction| instruction.offset.0).collect()
+}
+
+/// Returns the element covering the rest of the line from the 1-based `line` and `column`.
+///
+/// Without a column, the element starts at the first non-whitespace character of the line.
+fn source_element(content: &str, index: u32, line: u32, column: Option<u32>) -> SourceElement {
+    let line_start: usize =
+        content.split_inclusive('\n').take(line.saturating_sub(1) as usize).map(str::len).sum();
+    let text = content[line_start..].lines().next().unwrap_or_default();
+    let column = match column {
+        Some(column) => (column.saturating_sub(1) as usize).min(text.len()),
+        None => text.len() - text.trim_start().len(),
+    };
+    let length = text.get(column..).unwrap_or_default().trim_end().len();
+    SourceElement::from_range((line_start + column) as u32, length as u32, Some(index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use polkavm_common::{
+        program::{
+            Instruction, SECTION_OPT_DEBUG_LINE_PROGRAMS, SECTION_OPT_DEBUG_LINE_PROGRAM_RANGES,
+            SECTION_OPT_DEBUG_STRINGS, VERSION_DEBUG_LINE_PROGRAM_V1,
+        },
+        writer::ProgramBlobBuilder,
+    };
+
+    const SOURCE: &str = "contract A {\n    function f() public {\n        x = 1;\n    }\n}\n";
+
+    #[rustfmt::skip]
+    const LINE_PROGRAM: &[u8] = &[
+        VERSION_DEBUG_LINE_PROGRAM_V1,
+        10, 1, // set stack depth: 1
+        7, 1, // set path: "src/A.sol"
+        8, 3, // set line: 3
+        9, 9, // set column: 9
+        14, // finish instruction
+        11, // increment line
+        9, 0, // unset column
+        14, // finish instruction
+        0, // finish program
+    ];
+
+    /// Builds a blob of three instructions, with debug information for the first two.
+    fn blob(debug_info: bool) -> Vec<u8> {
+        let mut builder = ProgramBlobBuilder::new_64bit();
+        builder.set_code(
+            &[Instruction::fallthrough, Instruction::fallthrough, Instruction::trap],
+            &[],
+        );
+        if debug_info {
+            // the empty string at offset 0 stands for unknown paths
+            builder.add_custom_section(SECTION_OPT_DEBUG_STRINGS, b"\0\x09src/A.sol".to_vec());
+            builder.add_custom_section(SECTION_OPT_DEBUG_LINE_PROGRAMS, LINE_PROGRAM.to_vec());
+            builder.add_custom_section(
+                SECTION_OPT_DEBUG_LINE_PROGRAM_RANGES,
+                [0u32, 2, 1].into_iter().flat_map(u32::to_le_bytes).collect(),
+            );
+        }
+        builder.into_vec().unwrap()
+    }
+
+    #[test]
+    fn can_map_debug_info_to_sources() {
+        let map =
+            polkavm_source_map(&blob(true), |path| (path == "src/A.sol").then_some((3, SOURCE)))
+                .unwrap()
+                .unwrap();
+
+        let x = map.get(0).unwrap();
+        assert_eq!(&SOURCE[x.offset() as usize..][..x.length() as usize], "x = 1;");
+        assert_eq!(x.index(), Some(3));
+        let end = map.get(1).unwrap();
+        assert_eq!(&SOURCE[end.offset() as usize..][..end.length() as usize], "}");
+        assert!(map.get(2).is_none());
+
+        let unknown = polkavm_source_map(&blob(true), |_| None).unwrap().unwrap();
+        assert!(unknown.get(0).is_none());
+
+        assert!(polkavm_source_map(&blob(false), |_| None).unwrap().is_none());
+        assert!(polkavm_source_map(b"PVM\0", |_| None).is_err());
+    }
+
+    #[test]
+    fn can_read_debug_lines() {
+        let line = |start, end, line, column| PolkaVmDebugLine {
+            start,
+            end,
+            path: Some("src/A.sol".to_string()),
+            line: Some(line),
+            column,
+        };
+        assert_eq!(
+            polkavm_debug_lines(&blob(true)).unwrap().unwrap(),
+            [line(0, 1, 3, Some(9)), line(1, 2, 4, None)]
+        );
+        assert!(polkavm_debug_lines(&blob(false)).unwrap().is_none());
+    }
+}
@@ -1,11 +1,14 @@
 use crate::{
-    Ast, CompactBytecode, CompactContract, CompactContractBytecode, CompactContractBytecodeCow,
-    CompactDeployedBytecode, DevDoc, Ewasm, FunctionDebugData, GasEstimates, GeneratedSource,
-    Metadata, Offsets, SourceFile, StorageLayout, UserDoc,
+    sourcemap::PolkaVmSourceMap, Ast, CompactBytecode, CompactContract, CompactContractBytecode,
+    CompactContractBytecodeCow, CompactDeployedBytecode, DevDoc, Ewasm, FunctionDebugData,
+    GasEstimates, GeneratedSource, Metadata, Offsets, SourceFile, StorageLayout, UserDoc,
 };
 use alloy_json_abi::JsonAbi;
 use serde::{Deserialize, Serialize};
-use std::{borrow::Cow, collections::BTreeMap};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+};
 
 /// Represents the `Artifact` that `ConfigurableArtifacts` emits.
 ///
@@ -24,6 +27,9 @@ pub struct ConfigurableContractArtifact {
     pub assembly: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub legacy_assembly: Option<serde_json::Value>,
+    /// The PolkaVM assembly of contracts compiled by `resolc`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub polkavm_assembly: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub opcodes: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -56,6 +62,17 @@ pub struct ConfigurableContractArtifact {
     pub ewasm: Option<Ewasm>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ast: Option<Ast>,
+    /// The contract factory dependencies, mapping the hash of a dependency's code to its
+    /// `<path>:<name>` identifier
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub factory_dependencies: Option<BTreeMap<String, String>>,
+    /// The libraries that still need to be linked, mapping a source file to the names of the
+    /// libraries it declares
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub missing_libraries: Option<BTreeMap<String, BTreeSet<String>>>,
+    /// The source map of the PolkaVM code, mapping instruction offsets to source elements
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub polkavm_source_map: Option<PolkaVmSourceMap>,
     /// The identifier of the source file
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub id: Option<u32>,
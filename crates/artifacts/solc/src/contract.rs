@@ -4,12 +4,17 @@ use crate::{
     bytecode::{
         Bytecode, BytecodeObject, CompactBytecode, CompactDeployedBytecode, DeployedBytecode,
     },
-    serde_helpers, DevDoc, Evm, Ewasm, LosslessMetadata, Offsets, StorageLayout, UserDoc,
+    serde_helpers,
+    sourcemap::PolkaVmSourceMap,
+    DevDoc, Evm, Ewasm, LosslessMetadata, Offsets, StorageLayout, UserDoc,
 };
 use alloy_json_abi::JsonAbi;
 use alloy_primitives::Bytes;
 use serde::{Deserialize, Serialize};
-use std::{borrow::Cow, collections::BTreeMap};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+};
 
 /// Represents a compiled solidity contract
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -44,6 +49,21 @@ pub struct Contract {
     pub ir_optimized: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ir_optimized_ast: Option<serde_json::Value>,
+    /// The contract factory dependencies, mapping the hash of a dependency's code to its
+    /// `<path>:<name>` identifier. Only emitted by `resolc`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub factory_dependencies: Option<BTreeMap<String, String>>,
+    /// The libraries that still need to be linked, mapping a source file to the names of the
+    /// libraries it declares. Only emitted by `resolc`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub missing_libraries: Option<BTreeMap<String, BTreeSet<String>>>,
+    /// The source map of the PolkaVM code, derived from the debug information `resolc` embeds
+    /// into the blob. Only available if `resolc` emits debug information.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub polkavm_source_map: Option<PolkaVmSourceMap>,
+    /// The PolkaVM assembly of the contract. Only emitted by `resolc`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub polkavm_assembly: Option<String>,
 }
 
 impl<'a> From<&'a Contract> for CompactContractBytecodeCow<'a> {
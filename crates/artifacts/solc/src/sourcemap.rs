@@ -1,4 +1,5 @@
-use std::{fmt, fmt::Write, iter::Peekable, str::CharIndices};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{collections::BTreeMap, fmt, fmt::Write, iter::Peekable, str::CharIndices, str::FromStr};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Jump {
@@ -212,6 +213,14 @@ impl SourceElement {
         Self { offset: 0, length: 0, index: -1, jump_and_modifier_depth: 0 }
     }
 
+    /// Creates a new source element for a regular instruction covering the given source range.
+    pub fn from_range(offset: u32, length: u32, index: Option<u32>) -> Self {
+        let mut element =
+            Self { offset, length, index: index.map_or(-1, |index| index as i32), ..Self::new() };
+        element.set_jump(Jump::Regular);
+        element
+    }
+
     /// Creates a new source element with default values.
     #[deprecated = "use `new` instead"]
     pub fn new_invalid() -> Self {
@@ -575,6 +584,100 @@ pub fn parse(input: &str) -> Result<SourceMap, SyntaxError> {
     })
 }
 
+/// A source map of PolkaVM code.
+///
+/// Unlike EVM instructions, which are mapped by their index in a [`SourceMap`], PolkaVM
+/// instructions are addressed by their byte offset in the code. Every element is keyed by the
+/// offset of the first instruction it covers and covers all instructions up to the next element.
+/// Elements without a source index mark code without debug information.
+///
+/// (De)serialized as `<code offset>:<source element>` entries separated by semicolons.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PolkaVmSourceMap(BTreeMap<u32, SourceElement>);
+
+impl PolkaVmSourceMap {
+    /// Creates an empty source map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps the instructions starting at the given code offset to the source element.
+    pub fn insert(&mut self, code_offset: u32, element: SourceElement) {
+        self.0.insert(code_offset, element);
+    }
+
+    /// Returns the source element of the instruction at the given code offset.
+    ///
+    /// Returns `None` if the instruction is not covered by the debug information.
+    pub fn get(&self, code_offset: u32) -> Option<&SourceElement> {
+        self.0
+            .range(..=code_offset)
+            .next_back()
+            .map(|(_, element)| element)
+            .filter(|element| element.index().is_some())
+    }
+
+    /// Returns the code offsets and source elements, sorted by code offset.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &SourceElement)> {
+        self.0.iter().map(|(offset, element)| (*offset, element))
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Display for PolkaVmSourceMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (offset, element)) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_char(';')?;
+            }
+            write!(f, "{offset}:{element}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for PolkaVmSourceMap {
+    type Err = SyntaxError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut map = Self::new();
+        for entry in s.split(';').filter(|entry| !entry.is_empty()) {
+            let (offset, element) = entry
+                .split_once(':')
+                .ok_or_else(|| SyntaxError::new(None, format!("missing code offset: {entry}")))?;
+            let offset = offset
+                .parse()
+                .map_err(|_| SyntaxError::new(None, format!("invalid code offset: {offset}")))?;
+            let element = parse(element)?.pop().ok_or_else(|| {
+                SyntaxError::new(None, format!("missing source element: {entry}"))
+            })?;
+            map.insert(offset, element);
+        }
+        Ok(map)
+    }
+}
+
+impl Serialize for PolkaVmSourceMap {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PolkaVmSourceMap {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -622,6 +725,25 @@ mod tests {
         parse_test(s);
     }
 
+    #[test]
+    fn polkavm_source_map() {
+        let mut map = PolkaVmSourceMap::new();
+        map.insert(0, SourceElement::from_range(10, 5, Some(0)));
+        map.insert(6, SourceElement::from_range(20, 3, Some(1)));
+        map.insert(12, SourceElement::new());
+
+        assert_eq!(map.get(4).unwrap().offset(), 10);
+        assert_eq!(map.get(6).unwrap().index(), Some(1));
+        assert_eq!(map.get(11).unwrap().jump(), Jump::Regular);
+        assert!(map.get(12).is_none());
+
+        let s = map.to_string();
+        assert_eq!(s, "0:10:5:0:-:0;6:20:3:1:-:0;12:0:0:-1:i:0");
+        assert_eq!(s.parse::<PolkaVmSourceMap>().unwrap(), map);
+        assert_eq!(serde_json::to_string(&map).unwrap(), format!("\"{s}\""));
+        assert!("0".parse::<PolkaVmSourceMap>().is_err());
+    }
+
     // https://github.com/foundry-rs/foundry/issues/8986
     #[test]
     fn univ4_deployer() {
@@ -84,6 +84,10 @@ impl From<VyperContract> for solc_artifacts::Contract {
             ewasm: None,
             ir_optimized: None,
             ir_optimized_ast: None,
+            factory_dependencies: None,
+            missing_libraries: None,
+            polkavm_source_map: None,
+            polkavm_assembly: None,
         }
     }
 }
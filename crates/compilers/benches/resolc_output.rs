@@ -0,0 +1,132 @@
+//! Compares the peak memory usage and duration of compiling a project with a large `resolc`
+//! standard json output via [`Resolc::compile_output`], which buffers the output before it's
+//! deserialized, and via [`Resolc::compile_standard_json`], which deserializes it while it is being
+//! read.
+//!
+//! `resolc` is replaced by a script printing a generated output, so only the handling of the output
+//! is measured. Run with `cargo bench -p foundry-compilers --bench resolc_output`.
+//!
+//! [`Resolc::compile_output`]: foundry_compilers::resolc::Resolc::compile_output
+//! [`Resolc::compile_standard_json`]: foundry_compilers::resolc::Resolc::compile_standard_json
+
+use foundry_compilers::{
+    artifacts::resolc::ResolcCompilerOutput,
+    resolc::{Resolc, ResolcInput, ResolcLocator},
+    solc::{Solc, SolcCompiler},
+};
+use semver::{Version, VersionReq};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+/// Number of contracts in the generated output.
+const CONTRACTS: usize = 200;
+/// Size of every PolkaVM blob in bytes.
+const BLOB_SIZE: usize = 64 * 1024;
+
+/// Tracks the current and the peak number of allocated bytes.
+struct CountingAlloc;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let allocated = ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+            PEAK.fetch_max(allocated, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Writes an output with `CONTRACTS` contracts, each with a blob and its assembly.
+fn write_output(path: &Path) {
+    let mut file = std::io::BufWriter::new(fs::File::create(path).unwrap());
+    let blob = "50564d00".repeat(BLOB_SIZE / 4);
+    let assembly = "    a0 = a0 + 0x1\n".repeat(BLOB_SIZE / 16);
+    write!(file, r#"{{"contracts":{{"#).unwrap();
+    for i in 0..CONTRACTS {
+        if i > 0 {
+            write!(file, ",").unwrap();
+        }
+        write!(
+            file,
+            r#""src/C{i}.sol":{{"C{i}":{{"abi":[],"evm":{{"assembly":{assembly:?},"bytecode":{{"object":"{blob}"}},"deployedBytecode":{{"object":"{blob}"}}}}}}}}"#
+        )
+        .unwrap();
+    }
+    write!(file, r#"}},"version":"0.8.28"}}"#).unwrap();
+    file.flush().unwrap();
+}
+
+/// Runs `f` and returns its duration and the peak memory allocated in addition to the memory
+/// allocated before.
+fn measure(f: impl FnOnce() -> ResolcCompilerOutput) -> (Duration, usize) {
+    let baseline = ALLOCATED.load(Ordering::Relaxed);
+    PEAK.store(baseline, Ordering::Relaxed);
+    let start = Instant::now();
+    let output = f();
+    let elapsed = start.elapsed();
+    assert_eq!(output.contracts.len(), CONTRACTS);
+    drop(output);
+    (elapsed, PEAK.load(Ordering::Relaxed) - baseline)
+}
+
+/// Writes a script standing in for `resolc`, which prints the output at `output`.
+#[cfg(unix)]
+fn stub_resolc(dir: &Path, output: &Path) -> PathBuf {
+    use std::os::unix::fs::PermissionsExt;
+
+    let path = dir.join("resolc");
+    fs::write(&path, format!("#!/bin/sh\ncat > /dev/null\ncat '{}'\n", output.display())).unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+    path
+}
+
+#[cfg(unix)]
+fn main() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("output.json");
+    write_output(&path);
+    let size = fs::metadata(&path).unwrap().len();
+
+    let solc = Solc::new_with_version("solc", Version::new(0, 8, 28));
+    let resolc = Resolc {
+        resolc: stub_resolc(dir.path(), &path),
+        resolc_version: Version::new(0, 1, 0),
+        supported_solc_versions: VersionReq::STAR,
+        solc: SolcCompiler::Specific(solc.clone()),
+        locator: ResolcLocator::empty(),
+    };
+    let input = ResolcInput::default();
+
+    let buffered = measure(|| {
+        let stdout = resolc.compile_output::<ResolcInput>(&solc, &input).unwrap();
+        serde_json::from_slice(&stdout).unwrap()
+    });
+    let streamed = measure(|| resolc.compile_standard_json(&solc, &input).unwrap());
+
+    let mib = |bytes: usize| bytes as f64 / (1024.0 * 1024.0);
+    println!("output size: {:.1} MiB", mib(size as usize));
+    for (name, (elapsed, peak)) in [("buffered", buffered), ("streamed", streamed)] {
+        println!("{name:>10}: peak {:>7.1} MiB in {elapsed:?}", mib(peak));
+    }
+}
+
+#[cfg(not(unix))]
+fn main() {}
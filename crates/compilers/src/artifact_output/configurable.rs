@@ -9,8 +9,8 @@
 //! addition to that some output values can also be emitted as standalone files.
 
 use crate::{
-    sources::VersionedSourceFile, Artifact, ArtifactFile, ArtifactOutput, SolcConfig, SolcError,
-    SourceFile,
+    sources::VersionedSourceFile, Artifact, ArtifactFile, ArtifactOutput, OutputCapabilities,
+    SolcConfig, SolcError, SourceFile,
 };
 use alloy_json_abi::JsonAbi;
 use alloy_primitives::hex;
@@ -21,6 +21,7 @@ use foundry_compilers_artifacts::{
         BytecodeOutputSelection, ContractOutputSelection, DeployedBytecodeOutputSelection,
         EvmOutputSelection, EwasmOutputSelection,
     },
+    resolc::{is_polkavm_blob, sourcemap::polkavm_debug_lines},
     BytecodeObject, ConfigurableContractArtifact, Evm, Ewasm, GeneratedSource, LosslessMetadata,
     Metadata, Settings,
 };
@@ -107,6 +108,7 @@ impl ConfigurableArtifacts {
             generated_sources,
             source_map,
             opcodes,
+            polkavm_assembly,
             __non_exhaustive,
         } = self.additional_values;
 
@@ -131,7 +133,11 @@ impl ConfigurableArtifacts {
         if gas_estimates {
             selection.push(EvmOutputSelection::GasEstimates.into());
         }
-        if assembly || self.additional_files.assembly {
+        if assembly
+            || polkavm_assembly
+            || self.additional_files.assembly
+            || self.additional_files.polkavm_assembly
+        {
             selection.push(EvmOutputSelection::Assembly.into());
         }
         if legacy_assembly || self.additional_files.legacy_assembly {
@@ -165,6 +171,15 @@ impl ConfigurableArtifacts {
         }
         selection
     }
+
+    /// Returns the outputs of [`Self::output_selection`] a compiler with the given capabilities
+    /// doesn't emit, see [`Compiler::output_capabilities`](crate::Compiler::output_capabilities).
+    pub fn unsupported_outputs(
+        &self,
+        capabilities: &OutputCapabilities,
+    ) -> Vec<ContractOutputSelection> {
+        capabilities.unsupported(&self.output_selection())
+    }
 }
 
 impl ArtifactOutput for ConfigurableArtifacts {
@@ -213,6 +228,7 @@ impl ArtifactOutput for ConfigurableArtifacts {
         let mut artifact_method_identifiers = None;
         let mut artifact_assembly = None;
         let mut artifact_legacy_assembly = None;
+        let mut artifact_polkavm_assembly = None;
         let mut artifact_storage_layout = None;
         let mut artifact_transient_storage_layout = None;
         let mut generated_sources = None;
@@ -230,6 +246,10 @@ impl ArtifactOutput for ConfigurableArtifacts {
             ewasm,
             ir_optimized,
             ir_optimized_ast,
+            factory_dependencies,
+            missing_libraries,
+            polkavm_source_map,
+            polkavm_assembly,
         } = contract;
 
         if self.additional_values.metadata {
@@ -262,6 +282,9 @@ impl ArtifactOutput for ConfigurableArtifacts {
         if self.additional_values.transient_storage_layout {
             artifact_transient_storage_layout = Some(transient_storage_layout);
         }
+        if self.additional_values.polkavm_assembly {
+            artifact_polkavm_assembly = polkavm_assembly;
+        }
 
         if let Some(evm) = evm {
             let Evm {
@@ -308,6 +331,7 @@ impl ArtifactOutput for ConfigurableArtifacts {
             deployed_bytecode: artifact_deployed_bytecode,
             assembly: artifact_assembly,
             legacy_assembly: artifact_legacy_assembly,
+            polkavm_assembly: artifact_polkavm_assembly,
             opcodes,
             function_debug_data: artifact_function_debug_data,
             method_identifiers: artifact_method_identifiers,
@@ -325,6 +349,9 @@ impl ArtifactOutput for ConfigurableArtifacts {
             id: source_file.as_ref().map(|s| s.id),
             ast: source_file.and_then(|s| s.ast.clone()),
             generated_sources: generated_sources.unwrap_or_default(),
+            factory_dependencies,
+            missing_libraries,
+            polkavm_source_map,
         }
     }
 
@@ -359,6 +386,9 @@ impl ArtifactOutput for ConfigurableArtifacts {
             generated_sources,
             bytecode: _,
             deployed_bytecode: _,
+            polkavm_assembly,
+            polkavm_blob: _,
+            polkavm_debug_info: _,
             __non_exhaustive: _,
         } = self.additional_files;
 
@@ -386,6 +416,12 @@ impl ArtifactOutput for ConfigurableArtifacts {
         if source_map && artifact.get_source_map_str().is_none() {
             return Ok(true);
         }
+        if polkavm_assembly
+            && artifact.polkavm_assembly.is_none()
+            && is_polkavm(artifact.bytecode.as_ref().map(|b| &b.object))
+        {
+            return Ok(true);
+        }
         if generated_sources {
             // We can't check if generated sources are missing or just empty.
             return Ok(true);
@@ -426,6 +462,16 @@ impl ArtifactOutput for ConfigurableArtifacts {
                     self.additional_files.process_metadata(artifact.metadata.as_ref(), file)?;
                     self.additional_files
                         .process_source_map(artifact.get_source_map_str().as_deref(), file)?;
+                    self.additional_files
+                        .process_polkavm_assembly(artifact.polkavm_assembly.as_deref(), file)?;
+                    self.additional_files.process_polkavm_blob(
+                        artifact.bytecode.as_ref().map(|b| &b.object),
+                        file,
+                    )?;
+                    self.additional_files.process_polkavm_debug_info(
+                        artifact.bytecode.as_ref().map(|b| &b.object),
+                        file,
+                    )?;
                 }
             }
         }
@@ -455,6 +501,8 @@ pub struct ExtraOutputValues {
     pub generated_sources: bool,
     pub source_map: bool,
     pub opcodes: bool,
+    /// The PolkaVM assembly of contracts compiled by `resolc`
+    pub polkavm_assembly: bool,
 
     /// PRIVATE: This structure may grow, As such, constructing this structure should
     /// _always_ be done using a public constructor or update syntax:
@@ -490,6 +538,7 @@ impl ExtraOutputValues {
             generated_sources: true,
             source_map: true,
             opcodes: true,
+            polkavm_assembly: true,
             __non_exhaustive: (),
         }
     }
@@ -528,6 +577,7 @@ impl ExtraOutputValues {
                         config.generated_sources = true;
                         config.source_map = true;
                         config.opcodes = true;
+                        config.polkavm_assembly = true;
                     }
                     EvmOutputSelection::Assembly => {
                         config.assembly = true;
@@ -586,6 +636,17 @@ pub struct ExtraOutputFiles {
     pub generated_sources: bool,
     pub bytecode: bool,
     pub deployed_bytecode: bool,
+    /// The PolkaVM assembly of contracts compiled by `resolc`, written as `<Name>.pvmasm`
+    pub polkavm_assembly: bool,
+    /// The PolkaVM blob of contracts compiled by `resolc`, written as `<Name>.polkavm`
+    ///
+    /// The blob includes the debug information if `resolc` is configured to emit it.
+    pub polkavm_blob: bool,
+    /// The debug information embedded into PolkaVM blobs, written as `<Name>.pvmdbg.json`
+    ///
+    /// Lists the instruction ranges of the blob with the source locations they were compiled
+    /// from. Only written if `resolc` is configured to emit debug information.
+    pub polkavm_debug_info: bool,
 
     /// PRIVATE: This structure may grow, As such, constructing this structure should
     /// _always_ be done using a public constructor or update syntax:
@@ -614,6 +675,9 @@ impl ExtraOutputFiles {
             generated_sources: true,
             bytecode: true,
             deployed_bytecode: true,
+            polkavm_assembly: true,
+            polkavm_blob: true,
+            polkavm_debug_info: true,
             __non_exhaustive: (),
         }
     }
@@ -645,6 +709,9 @@ impl ExtraOutputFiles {
                         config.source_map = true;
                         config.bytecode = true;
                         config.deployed_bytecode = true;
+                        config.polkavm_assembly = true;
+                        config.polkavm_blob = true;
+                        config.polkavm_debug_info = true;
                     }
                     EvmOutputSelection::Assembly => {
                         config.assembly = true;
@@ -814,6 +881,53 @@ impl ExtraOutputFiles {
         Ok(())
     }
 
+    fn process_polkavm_assembly(&self, asm: Option<&str>, file: &Path) -> Result<(), SolcError> {
+        if self.polkavm_assembly {
+            if let Some(asm) = asm {
+                let file = file.with_extension("pvmasm");
+                fs::write(&file, asm).map_err(|err| SolcError::io(err, file))?
+            }
+        }
+        Ok(())
+    }
+
+    fn process_polkavm_blob(
+        &self,
+        bytecode: Option<&BytecodeObject>,
+        file: &Path,
+    ) -> Result<(), SolcError> {
+        if self.polkavm_blob && is_polkavm(bytecode) {
+            if let Some(blob) = bytecode.and_then(|b| b.as_bytes()) {
+                let file = file.with_extension("polkavm");
+                fs::write(&file, blob).map_err(|err| SolcError::io(err, file))?
+            }
+        }
+        Ok(())
+    }
+
+    fn process_polkavm_debug_info(
+        &self,
+        bytecode: Option<&BytecodeObject>,
+        file: &Path,
+    ) -> Result<(), SolcError> {
+        if self.polkavm_debug_info && is_polkavm(bytecode) {
+            let Some(blob) = bytecode.and_then(|b| b.as_bytes()) else { return Ok(()) };
+            let lines = match polkavm_debug_lines(blob) {
+                Ok(lines) => lines,
+                Err(err) => {
+                    warn!(?file, %err, "failed to read the PolkaVM debug information");
+                    None
+                }
+            };
+            if let Some(lines) = lines {
+                let file = file.with_extension("pvmdbg.json");
+                fs::write(&file, serde_json::to_string_pretty(&lines)?)
+                    .map_err(|err| SolcError::io(err, file))?
+            }
+        }
+        Ok(())
+    }
+
     /// Write the set values as separate files
     pub fn write_extras(&self, contract: &Contract, file: &Path) -> Result<(), SolcError> {
         self.process_abi(contract.abi.as_ref(), file)?;
@@ -837,6 +951,120 @@ impl ExtraOutputFiles {
             file,
         )?;
 
+        self.process_polkavm_assembly(contract.polkavm_assembly.as_deref(), file)?;
+        self.process_polkavm_blob(bytecode.map(|b| &b.object), file)?;
+        self.process_polkavm_debug_info(bytecode.map(|b| &b.object), file)?;
+
         Ok(())
     }
 }
+
+/// Returns `true` if the bytecode is a PolkaVM blob compiled by `resolc`.
+fn is_polkavm(bytecode: Option<&BytecodeObject>) -> bool {
+    bytecode.and_then(|b| b.as_bytes()).is_some_and(|code| is_polkavm_blob(code))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::hex;
+    use polkavm_common::{
+        program::{
+            Instruction, SECTION_OPT_DEBUG_LINE_PROGRAMS, SECTION_OPT_DEBUG_LINE_PROGRAM_RANGES,
+            SECTION_OPT_DEBUG_STRINGS, VERSION_DEBUG_LINE_PROGRAM_V1,
+        },
+        writer::ProgramBlobBuilder,
+    };
+
+    /// Builds a PolkaVM blob with debug information mapping its instruction to `src/A.sol:3`.
+    fn blob() -> String {
+        let mut builder = ProgramBlobBuilder::new_64bit();
+        builder.set_code(&[Instruction::trap], &[]);
+        builder.add_custom_section(SECTION_OPT_DEBUG_STRINGS, b"\0\x09src/A.sol".to_vec());
+        builder.add_custom_section(
+            SECTION_OPT_DEBUG_LINE_PROGRAMS,
+            // set stack depth, path and line, finish instruction and program
+            vec![VERSION_DEBUG_LINE_PROGRAM_V1, 10, 1, 7, 1, 8, 3, 14, 0],
+        );
+        builder.add_custom_section(
+            SECTION_OPT_DEBUG_LINE_PROGRAM_RANGES,
+            [0u32, 1, 1].into_iter().flat_map(u32::to_le_bytes).collect(),
+        );
+        hex::encode(builder.into_vec().unwrap())
+    }
+
+    fn contract(value: serde_json::Value) -> Contract {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn can_emit_polkavm_outputs() {
+        let artifacts = ConfigurableArtifacts {
+            additional_values: ExtraOutputValues { polkavm_assembly: true, ..Default::default() },
+            additional_files: ExtraOutputFiles {
+                polkavm_assembly: true,
+                polkavm_blob: true,
+                polkavm_debug_info: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(artifacts.output_selection().contains(&EvmOutputSelection::Assembly.into()));
+
+        let blob = blob();
+        let polkavm = contract(serde_json::json!({
+            "abi": [],
+            "evm": { "bytecode": { "object": blob }, "deployedBytecode": { "object": blob } },
+            "polkavmAssembly": "a0 = a0 + 0x1"
+        }));
+        let artifact =
+            artifacts.contract_to_artifact(Path::new("A.sol"), "A", polkavm.clone(), None);
+        assert_eq!(artifact.polkavm_assembly.as_deref(), Some("a0 = a0 + 0x1"));
+        assert!(artifact.assembly.is_none());
+
+        let evm = contract(serde_json::json!({
+            "abi": [],
+            "evm": {
+                "assembly": "PUSH1 0x80",
+                "bytecode": { "object": "6080604052" },
+                "deployedBytecode": { "object": "6080604052" }
+            }
+        }));
+        let artifact = artifacts.contract_to_artifact(Path::new("B.sol"), "B", evm.clone(), None);
+        assert!(artifact.polkavm_assembly.is_none());
+
+        let tmp = tempfile::tempdir().unwrap();
+        let file = tmp.path().join("A.json");
+        artifacts.additional_files.write_extras(&polkavm, &file).unwrap();
+        assert_eq!(fs::read_to_string(file.with_extension("pvmasm")).unwrap(), "a0 = a0 + 0x1");
+        assert_eq!(fs::read(file.with_extension("polkavm")).unwrap(), hex::decode(&blob).unwrap());
+        let debug_info: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(file.with_extension("pvmdbg.json")).unwrap())
+                .unwrap();
+        assert_eq!(
+            debug_info,
+            serde_json::json!([{ "start": 0, "end": 1, "path": "src/A.sol", "line": 3 }])
+        );
+
+        let file = tmp.path().join("B.json");
+        artifacts.additional_files.write_extras(&evm, &file).unwrap();
+        assert!(!file.with_extension("pvmasm").exists());
+        assert!(!file.with_extension("polkavm").exists());
+        assert!(!file.with_extension("pvmdbg.json").exists());
+    }
+
+    #[test]
+    fn skips_debug_info_of_invalid_polkavm_blobs() {
+        let files = ExtraOutputFiles { polkavm_debug_info: true, ..Default::default() };
+        // the PolkaVM magic followed by a truncated blob
+        let invalid = contract(serde_json::json!({
+            "abi": [],
+            "evm": { "bytecode": { "object": "50564d00ffffff" } }
+        }));
+
+        let tmp = tempfile::tempdir().unwrap();
+        let file = tmp.path().join("A.json");
+        files.write_extras(&invalid, &file).unwrap();
+        assert!(!file.with_extension("pvmdbg.json").exists());
+    }
+}
@@ -37,7 +37,7 @@ use crate::{
         contracts::VersionedContracts,
         sources::{VersionedSourceFile, VersionedSourceFiles},
     },
-    CompilerContract, ProjectPathsConfig,
+    CompilationTarget, CompilerContract, ProjectPathsConfig,
 };
 
 /// Represents unique artifact metadata for identifying artifacts on output
@@ -290,7 +290,7 @@ impl<T> Artifacts<T> {
         &self,
     ) -> impl Iterator<Item = (ArtifactId, &T)> + '_ {
         self.0.iter().flat_map(|(source, contract_artifacts)| {
-            contract_artifacts.iter().flat_map(move |(_contract_name, artifacts)| {
+            contract_artifacts.values().flat_map(move |artifacts| {
                 artifacts.iter().filter_map(move |artifact| {
                     O::contract_name(&artifact.file).map(|name| {
                         (
@@ -316,7 +316,7 @@ impl<T> Artifacts<T> {
         self,
     ) -> impl Iterator<Item = (ArtifactId, T)> {
         self.0.into_iter().flat_map(|(source, contract_artifacts)| {
-            contract_artifacts.into_iter().flat_map(move |(_contract_name, artifacts)| {
+            contract_artifacts.into_values().flat_map(move |artifacts| {
                 let source = source.clone();
                 artifacts.into_iter().filter_map(move |artifact| {
                     O::contract_name(&artifact.file).map(|name| {
@@ -876,8 +876,9 @@ pub trait ArtifactOutput {
         });
         for file in files {
             for (name, versioned_contracts) in &contracts[file] {
-                let unique_versions =
-                    versioned_contracts.iter().map(|c| &c.version).collect::<HashSet<_>>();
+                let unique_versions = ctx.unique_versions_by_target(
+                    versioned_contracts.iter().map(|c| (c.profile.as_str(), &c.version)),
+                );
                 let unique_profiles =
                     versioned_contracts.iter().map(|c| &c.profile).collect::<HashSet<_>>();
                 let primary_profile = primary_profiles.get(file);
@@ -896,7 +897,7 @@ pub trait ArtifactOutput {
                         layout.artifacts.as_path(),
                         &contract.version,
                         &contract.profile,
-                        unique_versions.len() > 1,
+                        unique_versions[&ctx.target(&contract.profile)] > 1,
                         unique_profiles.len() > 1
                             && primary_profile.is_none_or(|p| p != &contract.profile),
                     );
@@ -940,7 +941,9 @@ pub trait ArtifactOutput {
         // any contract definition, which are not included in the `CompilerOutput` but we want to
         // create Artifacts for them regardless
         for (file, sources) in sources.as_ref().iter() {
-            let unique_versions = sources.iter().map(|s| &s.version).collect::<HashSet<_>>();
+            let unique_versions = ctx.unique_versions_by_target(
+                sources.iter().map(|s| (s.profile.as_str(), &s.version)),
+            );
             let unique_profiles = sources.iter().map(|s| &s.profile).collect::<HashSet<_>>();
             for source in sources {
                 if !non_standalone_sources.contains(file) {
@@ -967,7 +970,7 @@ pub trait ArtifactOutput {
                                 &layout.artifacts,
                                 &source.version,
                                 &source.profile,
-                                unique_versions.len() > 1,
+                                unique_versions[&ctx.target(&source.profile)] > 1,
                                 unique_profiles.len() > 1,
                             );
 
@@ -1037,6 +1040,13 @@ pub struct OutputContext<'a> {
     ///     └── a.sol
     /// ```
     pub existing_artifacts: BTreeMap<&'a Path, &'a CachedArtifacts>,
+    /// The target of every settings profile, see
+    /// [`CompilerSettings::target`](crate::CompilerSettings::target).
+    ///
+    /// Artifacts of profiles for different targets are told apart by their profile, so a
+    /// contract only gets a version suffix if it's compiled with multiple versions for the same
+    /// target. Profiles not in this map target the EVM.
+    pub profile_targets: BTreeMap<&'a str, CompilationTarget>,
 }
 
 // === impl OutputContext
@@ -1050,7 +1060,34 @@ impl<'a> OutputContext<'a> {
             .map(|(file, entry)| (file.as_path(), &entry.artifacts))
             .collect::<BTreeMap<_, _>>();
 
-        Self { existing_artifacts }
+        Self { existing_artifacts, profile_targets: Default::default() }
+    }
+
+    /// Sets the targets of the settings profiles, see [`Self::profile_targets`].
+    pub fn with_profile_targets(
+        mut self,
+        profile_targets: impl IntoIterator<Item = (&'a str, CompilationTarget)>,
+    ) -> Self {
+        self.profile_targets = profile_targets.into_iter().collect();
+        self
+    }
+
+    /// Returns the target of the settings profile.
+    pub fn target(&self, profile: &str) -> CompilationTarget {
+        self.profile_targets.get(profile).copied().unwrap_or_default()
+    }
+
+    /// Returns the number of distinct versions per target of the given `(profile, version)`
+    /// pairs.
+    fn unique_versions_by_target<'b>(
+        &self,
+        versions: impl IntoIterator<Item = (&'b str, &'b Version)>,
+    ) -> HashMap<CompilationTarget, usize> {
+        let mut unique = HashMap::<_, HashSet<_>>::new();
+        for (profile, version) in versions {
+            unique.entry(self.target(profile)).or_default().insert(version);
+        }
+        unique.into_iter().map(|(target, versions)| (target, versions.len())).collect()
     }
 
     /// Returns the path of the already existing artifact for the `contract` of the `file` compiled
@@ -1183,6 +1220,25 @@ mod tests {
         assert_artifact::<serde_json::Value>();
     }
 
+    #[test]
+    fn counts_versions_per_target() {
+        let solc = Version::new(0, 8, 28);
+        let resolc = Version::new(0, 1, 0);
+        let ctx = OutputContext::default().with_profile_targets([
+            ("default", CompilationTarget::Evm),
+            ("polkavm", CompilationTarget::PolkaVm),
+        ]);
+        assert_eq!(ctx.target("unknown"), CompilationTarget::Evm);
+
+        let versions = ctx.unique_versions_by_target([("default", &solc), ("polkavm", &resolc)]);
+        assert_eq!(versions[&CompilationTarget::Evm], 1);
+        assert_eq!(versions[&CompilationTarget::PolkaVm], 1);
+
+        let versions = OutputContext::default()
+            .unique_versions_by_target([("default", &solc), ("polkavm", &resolc)]);
+        assert_eq!(versions[&CompilationTarget::Evm], 2);
+    }
+
     #[test]
     fn can_find_alternate_paths() {
         let mut already_taken = HashSet::new();
@@ -27,6 +27,15 @@ pub struct BuildInfo<I, O> {
     pub compiler_version: Version,
     pub input: I,
     pub output: O,
+    /// The `resolc` version, for builds compiled by `resolc`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolc_version: Option<Version>,
+    /// The long version of the `solc` invoked by `resolc`, if reported by `resolc`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub solc_long_version: Option<Version>,
+    /// The revive version reported by `resolc`, for builds compiled by `resolc`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revive_version: Option<String>,
 }
 
 impl<I: DeserializeOwned, O: DeserializeOwned> BuildInfo<I, O> {
@@ -36,6 +45,18 @@ impl<I: DeserializeOwned, O: DeserializeOwned> BuildInfo<I, O> {
     }
 }
 
+/// Versions of the tools behind the compiler, reported with the [`CompilerOutput`] and recorded in
+/// the [`BuildContext`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ToolchainVersions {
+    /// The `resolc` version, for builds compiled by `resolc`.
+    pub resolc_version: Option<Version>,
+    /// The long version of the `solc` invoked by `resolc`, if reported by `resolc`.
+    pub solc_long_version: Option<Version>,
+    /// The revive version reported by `resolc`, for builds compiled by `resolc`.
+    pub revive_version: Option<String>,
+}
+
 /// Additional context we cache for each compiler run.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BuildContext<L> {
@@ -43,6 +64,15 @@ pub struct BuildContext<L> {
     pub source_id_to_path: BTreeMap<u32, PathBuf>,
     /// Language of the compiler.
     pub language: L,
+    /// The `resolc` version, for builds compiled by `resolc`.
+    #[serde(default, rename = "resolcVersion", skip_serializing_if = "Option::is_none")]
+    pub resolc_version: Option<Version>,
+    /// The long version of the `solc` invoked by `resolc`, if reported by `resolc`.
+    #[serde(default, rename = "solcLongVersion", skip_serializing_if = "Option::is_none")]
+    pub solc_long_version: Option<Version>,
+    /// The revive version reported by `resolc`, for builds compiled by `resolc`.
+    #[serde(default, rename = "reviveVersion", skip_serializing_if = "Option::is_none")]
+    pub revive_version: Option<String>,
 }
 
 impl<L: Language> BuildContext<L> {
@@ -59,7 +89,16 @@ impl<L: Language> BuildContext<L> {
             }
         }
 
-        Ok(Self { source_id_to_path, language: input.language() })
+        let ToolchainVersions { resolc_version, solc_long_version, revive_version } =
+            output.toolchain.clone();
+
+        Ok(Self {
+            source_id_to_path,
+            language: input.language(),
+            resolc_version,
+            solc_long_version,
+            revive_version,
+        })
     }
 
     pub fn join_all(&mut self, root: &Path) {
@@ -159,4 +198,53 @@ mod tests {
         let _info: BuildInfo<SolcVersionedInput, CompilerOutput<Error, Contract>> =
             serde_json::from_str(&serde_json::to_string(&raw_info).unwrap()).unwrap();
     }
+
+    #[test]
+    fn build_context_resolc_versions_serde() {
+        let input = SolcVersionedInput::build(
+            Sources::from([(PathBuf::from("input.sol"), Source::new(""))]),
+            Default::default(),
+            SolcLanguage::Solidity,
+            Version::new(0, 8, 28),
+        );
+        let output = CompilerOutput::<Error, Contract> {
+            toolchain: ToolchainVersions {
+                resolc_version: Some("0.1.0-dev.16+commit.a1b2c3d".parse().unwrap()),
+                solc_long_version: Some("0.8.28+commit.7893614a.Linux.gcc".parse().unwrap()),
+                revive_version: Some("1.0.0".to_string()),
+            },
+            ..Default::default()
+        };
+
+        let raw_info = RawBuildInfo::new(&input, &output, &Version::new(0, 1, 0), false).unwrap();
+        let context = &raw_info.build_context;
+        assert_eq!(context.resolc_version, Some("0.1.0-dev.16+commit.a1b2c3d".parse().unwrap()));
+        assert_eq!(
+            context.solc_long_version,
+            Some("0.8.28+commit.7893614a.Linux.gcc".parse().unwrap())
+        );
+        assert_eq!(context.revive_version.as_deref(), Some("1.0.0"));
+
+        let json = serde_json::to_string(&raw_info).unwrap();
+        let read: BuildContext<SolcLanguage> = serde_json::from_str(&json).unwrap();
+        assert_eq!(&read, context);
+        assert!(
+            serde_json::from_str::<serde_json::Value>(&json).unwrap()["resolcVersion"].is_string()
+        );
+
+        // the build info reads the versions recorded by the build context
+        let raw_info = RawBuildInfo::new(&input, &output, &Version::new(0, 1, 0), true).unwrap();
+        let info: BuildInfo<SolcVersionedInput, CompilerOutput<Error, Contract>> =
+            serde_json::from_str(&serde_json::to_string(&raw_info).unwrap()).unwrap();
+        assert_eq!(info.resolc_version, raw_info.build_context.resolc_version);
+        assert_eq!(info.solc_long_version, raw_info.build_context.solc_long_version);
+        assert_eq!(info.revive_version, raw_info.build_context.revive_version);
+
+        // `solc` builds don't record the fields
+        let output = CompilerOutput::<Error, Contract>::default();
+        let raw_info = RawBuildInfo::new(&input, &output, &Version::new(0, 8, 28), false).unwrap();
+        let json = serde_json::to_value(&raw_info).unwrap();
+        assert!(json.get("resolcVersion").is_none());
+        assert_eq!(raw_info.build_context.solc_long_version, None);
+    }
 }
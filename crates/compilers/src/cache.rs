@@ -2,7 +2,7 @@
 
 use crate::{
     buildinfo::RawBuildInfo,
-    compilers::{Compiler, CompilerSettings, Language},
+    compilers::{CompilationTarget, Compiler, CompilerSettings, Language},
     output::Builds,
     resolver::GraphEdges,
     ArtifactFile, ArtifactOutput, Artifacts, ArtifactsMap, Graph, OutputContext, Project,
@@ -842,19 +842,22 @@ impl<T: ArtifactOutput<CompilerContract = C::CompilerContract>, C: Compiler>
 
         let existing_profiles = self.project.settings_profiles().collect::<BTreeMap<_, _>>();
 
-        let mut dirty_profiles = HashSet::new();
+        // Profiles whose settings changed, mapped to the settings their artifacts were compiled
+        // with.
+        let mut dirty_profiles = HashMap::new();
         for (profile, settings) in &self.cache.profiles {
             if !existing_profiles.get(profile.as_str()).is_some_and(|p| p.can_use_cached(settings))
             {
                 trace!("dirty profile: {}", profile);
-                dirty_profiles.insert(profile.clone());
+                dirty_profiles.insert(profile.clone(), settings.clone());
             }
         }
 
-        for profile in &dirty_profiles {
+        for profile in dirty_profiles.keys() {
             self.cache.profiles.remove(profile);
         }
 
+        let cached_builds = &self.cached_builds;
         self.cache.files.retain(|_, entry| {
             // keep entries which already had no artifacts
             if entry.artifacts.is_empty() {
@@ -862,7 +865,22 @@ impl<T: ArtifactOutput<CompilerContract = C::CompilerContract>, C: Compiler>
             }
             entry.artifacts.retain(|_, artifacts| {
                 artifacts.retain(|_, artifacts| {
-                    artifacts.retain(|profile, _| !dirty_profiles.contains(profile));
+                    // artifacts of a dirty profile are only kept if the changed settings don't
+                    // affect the compiler which produced them
+                    artifacts.retain(|profile, artifact| {
+                        dirty_profiles.get(profile).is_none_or(|cached| {
+                            // builds compiled by `resolc` record its version
+                            let target = match cached_builds.get(&artifact.build_id) {
+                                Some(build) if build.resolc_version.is_some() => {
+                                    CompilationTarget::PolkaVm
+                                }
+                                _ => CompilationTarget::Evm,
+                            };
+                            existing_profiles
+                                .get(profile.as_str())
+                                .is_some_and(|p| p.can_use_cached_target(cached, target))
+                        })
+                    });
                     !artifacts.is_empty()
                 });
                 !artifacts.is_empty()
@@ -1142,10 +1160,13 @@ impl<'a, T: ArtifactOutput<CompilerContract = C::CompilerContract>, C: Compiler>
     }
 
     pub fn output_ctx(&self) -> OutputContext<'_> {
-        match self {
-            ArtifactsCache::Ephemeral(..) => Default::default(),
+        let ctx = match self {
+            ArtifactsCache::Ephemeral(..) => OutputContext::default(),
             ArtifactsCache::Cached(inner) => OutputContext::new(&inner.cache),
-        }
+        };
+        ctx.with_profile_targets(
+            self.project().settings_profiles().map(|(name, settings)| (name, settings.target())),
+        )
     }
 
     pub fn project(&self) -> &'a Project<C, T> {
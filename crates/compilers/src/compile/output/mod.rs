@@ -1,13 +1,16 @@
 //! The output of a compiled project
 use contracts::{VersionedContract, VersionedContracts};
-use foundry_compilers_artifacts::{CompactContractBytecode, CompactContractRef, Severity};
+use foundry_compilers_artifacts::{
+    resolc::{libraries::PolkaVmLibraries, IGNORABLE_ERROR_CODES},
+    CompactContractBytecode, CompactContractRef, ConfigurableContractArtifact, Contract, Severity,
+};
 use foundry_compilers_core::error::{SolcError, SolcIoError};
 use info::ContractInfoRef;
 use semver::Version;
 use serde::{Deserialize, Serialize};
 use sources::{VersionedSourceFile, VersionedSourceFiles};
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, HashMap, HashSet},
     fmt,
     ops::{Deref, DerefMut},
     path::{Path, PathBuf},
@@ -17,9 +20,10 @@ use yansi::Paint;
 use crate::{
     buildinfo::{BuildContext, RawBuildInfo},
     compilers::{
-        multi::MultiCompiler, CompilationError, Compiler, CompilerContract, CompilerOutput,
+        multi::MultiCompiler, resolc::PolkaVmContractSize, solc::SolcSettings, CompilationError,
+        Compiler, CompilerContract, CompilerOutput,
     },
-    Artifact, ArtifactId, ArtifactOutput, Artifacts, ConfigurableArtifacts,
+    Artifact, ArtifactId, ArtifactOutput, Artifacts, ConfigurableArtifacts, Project,
 };
 
 pub mod contracts;
@@ -461,6 +465,163 @@ impl<T: ArtifactOutput<CompilerContract = C::CompilerContract>, C: Compiler>
     }
 }
 
+impl<C: Compiler<CompilerContract = Contract>> ProjectCompileOutput<C, ConfigurableArtifacts> {
+    /// Returns the transitive closure of the factory dependencies of the given contract.
+    ///
+    /// Factory dependencies are emitted by `resolc` for contracts that deploy other contracts. The
+    /// returned artifacts are ordered so that every dependency precedes the contracts depending on
+    /// it, i.e. the code of each entry can be uploaded in order before instantiating the given
+    /// contract. The contract itself is not included.
+    ///
+    /// Only artifacts compiled by `resolc` are considered, and the dependencies are looked up
+    /// among the artifacts of the same profile and version as the contract depending on them.
+    ///
+    /// Returns an error if the contract or one of its dependencies can't be found, or if there are
+    /// multiple matching artifacts, e.g. of multiple PolkaVM profiles.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use foundry_compilers::{info::ContractInfo, Project};
+    ///
+    /// let project = Project::builder().build(Default::default())?;
+    /// let output = project.compile()?;
+    /// let info = ContractInfo::new("src/Factory.sol:Factory");
+    /// for (id, _artifact) in output.factory_dependencies(&info)? {
+    ///     println!("upload {}", id.identifier());
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn factory_dependencies<'a>(
+        &self,
+        info: impl Into<ContractInfoRef<'a>>,
+    ) -> Result<Vec<(ArtifactId, &ConfigurableContractArtifact)>, SolcError> {
+        let ContractInfoRef { path, name } = info.into();
+        let path = path.as_deref().map(Path::new);
+        let (id, artifact) = self.find_polkavm_artifact(path, &name, None)?;
+
+        let mut visited = HashSet::from([id.identifier()]);
+        let mut dependencies = Vec::new();
+        self.collect_factory_dependencies(&id, artifact, &mut visited, &mut dependencies)?;
+        Ok(dependencies)
+    }
+
+    /// Returns the PolkaVM artifacts which were compiled without the addresses of the libraries
+    /// they use, together with these libraries.
+    ///
+    /// Link the libraries to addresses and pass the result to [`Project::link_polkavm`] to
+    /// recompile the contracts with them.
+    pub fn unlinked_polkavm_artifacts(&self) -> Vec<(ArtifactId, PolkaVmLibraries)> {
+        self.artifact_ids()
+            .filter_map(|(id, artifact)| {
+                let libraries = PolkaVmLibraries::from_artifact(artifact);
+                libraries.has_missing().then_some((id, libraries))
+            })
+            .collect()
+    }
+
+    /// Recursively collects the factory dependencies of the given artifact in post-order.
+    fn collect_factory_dependencies<'a>(
+        &'a self,
+        id: &ArtifactId,
+        artifact: &'a ConfigurableContractArtifact,
+        visited: &mut HashSet<String>,
+        dependencies: &mut Vec<(ArtifactId, &'a ConfigurableContractArtifact)>,
+    ) -> Result<(), SolcError> {
+        let Some(factory_dependencies) = &artifact.factory_dependencies else {
+            return Ok(());
+        };
+
+        for dependency in factory_dependencies.values() {
+            let (path, name) = dependency.rsplit_once(':').ok_or_else(|| {
+                SolcError::msg(format!("invalid factory dependency identifier `{dependency}`"))
+            })?;
+            let (dependency_id, dependency) =
+                self.find_polkavm_artifact(Some(Path::new(path)), name, Some(id))?;
+
+            if visited.insert(dependency_id.identifier()) {
+                self.collect_factory_dependencies(
+                    &dependency_id,
+                    dependency,
+                    visited,
+                    dependencies,
+                )?;
+                dependencies.push((dependency_id, dependency));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Finds the artifact compiled by `resolc` with the given name whose source path ends with
+    /// `path`, restricted to the profile and version of `compiled_with` if set.
+    ///
+    /// Returns an error if there is no such artifact or if there are multiple.
+    fn find_polkavm_artifact(
+        &self,
+        path: Option<&Path>,
+        name: &str,
+        compiled_with: Option<&ArtifactId>,
+    ) -> Result<(ArtifactId, &ConfigurableContractArtifact), SolcError> {
+        let mut matches = self.artifact_ids().filter(|(id, _)| {
+            id.name == name
+                && path.is_none_or(|path| id.source.ends_with(path))
+                && self.builds.get(&id.build_id).is_some_and(|b| b.resolc_version.is_some())
+                && compiled_with
+                    .is_none_or(|other| id.profile == other.profile && id.version == other.version)
+        });
+        let found = matches.next().ok_or_else(|| {
+            SolcError::ArtifactNotFound(path.map(Into::into).unwrap_or_default(), name.to_string())
+        })?;
+        if let Some((other, _)) = matches.next() {
+            return Err(SolcError::msg(format!(
+                "multiple PolkaVM artifacts match `{name}`: {} and {}",
+                found.0.path.display(),
+                other.path.display()
+            )));
+        }
+        Ok(found)
+    }
+}
+
+impl<C: Compiler, T: ArtifactOutput<CompilerContract = C::CompilerContract>>
+    ProjectCompileOutput<C, T>
+where
+    C::Settings: Into<SolcSettings>,
+{
+    /// Returns the code sizes of all PolkaVM artifacts, together with the heap and stack sizes
+    /// configured in the profile of the `project` they were compiled with.
+    ///
+    /// EVM artifacts are skipped. See [`PolkaVmLimits`](crate::resolc::PolkaVmLimits) to report
+    /// contracts exceeding limits as diagnostics.
+    pub fn polkavm_size_report(
+        &self,
+        project: &Project<C, T>,
+    ) -> Vec<(ArtifactId, PolkaVmContractSize)> {
+        let memory_configs = project
+            .settings_profiles()
+            .map(|(name, settings)| {
+                let settings: SolcSettings = settings.clone().into();
+                let memory_config = settings.extra_settings.polkavm.map(|pvm| pvm.memory_config);
+                (name, memory_config.unwrap_or_default())
+            })
+            .collect::<HashMap<_, _>>();
+
+        self.artifact_ids()
+            .filter_map(|(id, artifact)| {
+                let blob = artifact.get_deployed_bytecode_bytes()?;
+                let initcode = artifact.get_bytecode_bytes();
+                let memory_config = memory_configs.get(id.profile.as_str()).cloned();
+                let size = PolkaVmContractSize::new(
+                    initcode.as_deref().unwrap_or(&blob),
+                    &blob,
+                    &memory_config.unwrap_or_default(),
+                )?;
+                Some((id, size))
+            })
+            .collect()
+    }
+}
+
 impl<C: Compiler, T: ArtifactOutput<CompilerContract = C::CompilerContract>>
     ProjectCompileOutput<C, T>
 {
@@ -818,8 +979,9 @@ impl<C: Compiler> AggregatedCompilerOutput<C> {
     ) -> bool {
         self.errors.iter().any(|err| {
             if err.is_error() {
-                // [Severity::Error] is always treated as an error
-                return true;
+                // [Severity::Error] is always treated as an error, unless it's an explicitly
+                // ignored missing libraries error
+                return !self.should_ignore(ignored_error_codes, ignored_file_paths, err);
             }
             // check if the filter is set to something higher than the error's severity
             if compiler_severity_filter.ge(&err.severity()) {
@@ -847,7 +1009,11 @@ impl<C: Compiler> AggregatedCompilerOutput<C> {
         ignored_file_paths: &[PathBuf],
         error: &C::CompilationError,
     ) -> bool {
-        if !error.is_warning() {
+        // errors are never ignored, apart from the `resolc` errors about missing libraries and
+        // exceeded PolkaVM limits
+        if !error.is_warning()
+            && error.error_code().is_none_or(|code| !IGNORABLE_ERROR_CODES.contains(&code))
+        {
             return false;
         }
 
@@ -938,3 +1104,172 @@ impl<C: Compiler> fmt::Display for OutputDiagnostics<'_, C> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        compilers::multi::{MultiCompilerError, MultiCompilerLanguage},
+        info::ContractInfo,
+        ArtifactFile,
+    };
+    use foundry_compilers_artifacts::{
+        resolc::{BLOB_SIZE_ERROR_CODE, MISSING_LIBRARIES_ERROR_CODE},
+        Error, SolcLanguage,
+    };
+
+    fn artifact_file(
+        source: &str,
+        name: &str,
+        deps: &[(&str, &str)],
+        build_id: &str,
+        profile: &str,
+    ) -> (PathBuf, String, ArtifactFile<ConfigurableContractArtifact>) {
+        let factory_dependencies = (!deps.is_empty())
+            .then(|| deps.iter().map(|(hash, id)| (hash.to_string(), id.to_string())).collect());
+        let artifact = ConfigurableContractArtifact { factory_dependencies, ..Default::default() };
+        let file = ArtifactFile {
+            artifact,
+            file: PathBuf::from(format!("out/{profile}/{source}/{name}.json")),
+            version: Version::new(0, 8, 28),
+            build_id: build_id.to_string(),
+            profile: profile.to_string(),
+        };
+        (PathBuf::from(format!("/root/{source}")), name.to_string(), file)
+    }
+
+    fn build_context(resolc_version: Option<&str>) -> BuildContext<MultiCompilerLanguage> {
+        BuildContext {
+            source_id_to_path: Default::default(),
+            language: SolcLanguage::Solidity.into(),
+            resolc_version: resolc_version.map(|version| version.parse().unwrap()),
+            solc_long_version: None,
+            revive_version: None,
+        }
+    }
+
+    /// Returns the output of a factory deploying two children which deploy the same grandchild,
+    /// compiled by `resolc` with each of the `profiles`.
+    fn factory_output(
+        profiles: &[&str],
+        evm: bool,
+    ) -> ProjectCompileOutput<MultiCompiler, ConfigurableArtifacts> {
+        let mut artifacts = Artifacts::<ConfigurableContractArtifact>::default();
+        let mut builds = BTreeMap::new();
+        let mut insert = |(source, name, file): (PathBuf, String, ArtifactFile<_>)| {
+            artifacts.0.entry(source).or_default().entry(name).or_default().push(file);
+        };
+        for &profile in profiles {
+            let build_id = format!("resolc-{profile}");
+            builds.insert(build_id.clone(), build_context(Some("0.1.0-dev.13")));
+            for (source, name, deps) in [
+                (
+                    "src/Factory.sol",
+                    "Factory",
+                    &[("0x01", "src/Child.sol:Child"), ("0x02", "src/Other.sol:Other")][..],
+                ),
+                ("src/Child.sol", "Child", &[("0x03", "src/Grandchild.sol:Grandchild")]),
+                ("src/Other.sol", "Other", &[("0x03", "src/Grandchild.sol:Grandchild")]),
+                ("src/Grandchild.sol", "Grandchild", &[]),
+            ] {
+                insert(artifact_file(source, name, deps, &build_id, profile));
+            }
+        }
+        if evm {
+            // EVM artifacts of the same contracts, which have no factory dependencies
+            builds.insert("solc".to_string(), build_context(None));
+            for (source, name) in [
+                ("src/Factory.sol", "Factory"),
+                ("src/Child.sol", "Child"),
+                ("src/Other.sol", "Other"),
+                ("src/Grandchild.sol", "Grandchild"),
+            ] {
+                insert(artifact_file(source, name, &[], "solc", "default"));
+            }
+        }
+
+        ProjectCompileOutput {
+            compiler_output: Default::default(),
+            compiled_artifacts: artifacts,
+            cached_artifacts: Default::default(),
+            ignored_error_codes: Default::default(),
+            ignored_file_paths: Default::default(),
+            compiler_severity_filter: Severity::Error,
+            builds: Builds(builds),
+        }
+    }
+
+    #[test]
+    fn can_resolve_factory_dependency_closure() {
+        let output = factory_output(&["default"], false);
+
+        let closure = output
+            .factory_dependencies(ContractInfo::new("src/Factory.sol:Factory"))
+            .unwrap()
+            .into_iter()
+            .map(|(id, _)| id.name)
+            .collect::<Vec<_>>();
+        assert_eq!(closure, ["Grandchild", "Child", "Other"]);
+
+        assert!(output.factory_dependencies(ContractInfo::new("Grandchild")).unwrap().is_empty());
+        assert!(matches!(
+            output.factory_dependencies(ContractInfo::new("Missing")),
+            Err(SolcError::ArtifactNotFound(_, _))
+        ));
+    }
+
+    #[test]
+    fn resolves_factory_dependencies_of_polkavm_artifacts() {
+        // with both targets, the EVM artifacts of the `default` profile are skipped
+        let output = factory_output(&["polkavm"], true);
+        let closure = output.factory_dependencies(ContractInfo::new("Factory")).unwrap();
+        assert_eq!(closure.len(), 3);
+        assert!(closure.iter().all(|(id, _)| id.profile == "polkavm"));
+
+        // the PolkaVM artifacts of multiple profiles are ambiguous
+        let output = factory_output(&["polkavm", "optimized-polkavm"], true);
+        let err = output.factory_dependencies(ContractInfo::new("Factory")).unwrap_err();
+        assert!(err.to_string().contains("multiple PolkaVM artifacts"), "{err}");
+    }
+
+    #[test]
+    fn missing_libraries_error_can_be_ignored() {
+        let error = Error {
+            source_location: None,
+            secondary_source_locations: Vec::new(),
+            r#type: "LinkerError".to_string(),
+            component: "general".to_string(),
+            severity: Severity::Error,
+            error_code: Some(MISSING_LIBRARIES_ERROR_CODE),
+            message: "Contract is missing libraries".to_string(),
+            formatted_message: None,
+        };
+        let mut output = AggregatedCompilerOutput::<MultiCompiler>::default();
+        output.errors.push(MultiCompilerError::Solc(error.clone()));
+
+        assert!(output.has_error(&[], &[], &Severity::Error));
+        assert!(!output.has_error(&[MISSING_LIBRARIES_ERROR_CODE], &[], &Severity::Error));
+
+        // other errors are never ignored
+        output.errors.push(MultiCompilerError::Solc(Error { error_code: Some(1234), ..error }));
+        assert!(output.has_error(&[MISSING_LIBRARIES_ERROR_CODE, 1234], &[], &Severity::Error));
+    }
+
+    #[test]
+    fn polkavm_limit_errors_can_be_ignored() {
+        let mut output = AggregatedCompilerOutput::<MultiCompiler>::default();
+        output.errors.push(MultiCompilerError::Solc(Error {
+            source_location: None,
+            secondary_source_locations: Vec::new(),
+            r#type: "Error".to_string(),
+            component: "general".to_string(),
+            severity: Severity::Error,
+            error_code: Some(BLOB_SIZE_ERROR_CODE),
+            message: "PolkaVM blob exceeds the size limit".to_string(),
+            formatted_message: None,
+        }));
+
+        assert!(output.has_error(&[], &[], &Severity::Error));
+        assert!(!output.has_error(&[BLOB_SIZE_ERROR_CODE], &[], &Severity::Error));
+    }
+}
@@ -112,13 +112,14 @@ use crate::{
     ArtifactOutput, CompilerSettings, Graph, Project, ProjectCompileOutput, ProjectPathsConfig,
     Sources,
 };
-use foundry_compilers_core::error::Result;
+use foundry_compilers_core::error::{Result, SolcError};
 use rayon::prelude::*;
 use semver::Version;
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     fmt::Debug,
     path::PathBuf,
+    sync::{Condvar, Mutex},
     time::Instant,
 };
 
@@ -172,6 +173,14 @@ impl<'a, T: ArtifactOutput<CompilerContract = C::CompilerContract>, C: Compiler>
     /// Multiple (`Solc` -> `Sources`) pairs can be compiled in parallel if the `Project` allows
     /// multiple `jobs`, see [`crate::Project::set_solc_jobs()`].
     pub fn with_sources(project: &'a Project<C, T>, mut sources: Sources) -> Result<Self> {
+        let missing_profiles = project.missing_target_profiles();
+        if !missing_profiles.is_empty() {
+            return Err(SolcError::msg(format!(
+                "missing settings profiles for the targets of the compiler: {}; \
+                 see `Project::add_target_profiles`",
+                missing_profiles.join(", ")
+            )));
+        }
         if let Some(filter) = &project.sparse_output {
             sources.retain(|f, _| filter.is_match(f))
         }
@@ -538,7 +547,7 @@ impl<L: Language, S: CompilerSettings> CompilerSources<'_, L, S> {
         cache.update_mocks(mocks);
 
         let results = if let Some(num_jobs) = jobs_cnt {
-            compile_parallel(&project.compiler, jobs, num_jobs)
+            compile_parallel(&project.compiler, jobs, num_jobs, &project.job_limits)
         } else {
             compile_sequential(&project.compiler, jobs)
         }?;
@@ -607,11 +616,14 @@ fn compile_parallel<'a, C: Compiler>(
     compiler: &C,
     jobs: Vec<(C::Input, &'a str, Vec<PathBuf>)>,
     num_jobs: usize,
+    limits: &CompilerJobLimits,
 ) -> CompilationResult<'a, C::Input, C::CompilationError, C::CompilerContract> {
     // need to get the currently installed reporter before installing the pool, otherwise each new
     // thread in the pool will get initialized with the default value of the `thread_local!`'s
     // localkey. This way we keep access to the reporter in the rayon pool
     let scoped_report = report::get_default(|reporter| reporter.clone());
+    let throttle = JobThrottle::new(limits);
+    let throttle = &throttle;
 
     // start a rayon threadpool that will execute all `Solc::compile()` processes
     let pool = rayon::ThreadPoolBuilder::new().num_threads(num_jobs).build().unwrap();
@@ -629,6 +641,7 @@ fn compile_parallel<'a, C: Compiler>(
                         vec![compiler.compiler_version(&input), input.version().clone()]
                     }
                 };
+                let _permit = throttle.acquire(&compiler.job_key(&input));
                 let start = Instant::now();
                 report::compiler_spawn(
                     &compiler.compiler_name(&input),
@@ -648,6 +661,102 @@ fn compile_parallel<'a, C: Compiler>(
     })
 }
 
+/// Limits for compiler processes running in parallel, in addition to
+/// [`Project::set_solc_jobs`](crate::Project::set_solc_jobs).
+///
+/// Compilers are identified by their [`Compiler::job_key`], e.g. [`Resolc::JOB_KEY`]. Some
+/// compilers, like `resolc` which drives LLVM, need far more memory than `solc`, so the number of
+/// their parallel jobs can be limited separately, or throttled by a memory budget shared by all
+/// jobs.
+///
+/// [`Resolc::JOB_KEY`]: crate::resolc::Resolc::JOB_KEY
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CompilerJobLimits {
+    /// Maximum number of parallel jobs per compiler.
+    pub jobs: BTreeMap<String, usize>,
+    /// Estimated peak memory of a single job per compiler, in bytes.
+    ///
+    /// Jobs of compilers without an estimate don't count against the memory budget.
+    pub job_memory: BTreeMap<String, u64>,
+    /// Maximum estimated memory of all parallel jobs combined, in bytes.
+    ///
+    /// A job exceeding the budget on its own is only run once no other job with a memory
+    /// estimate is running.
+    pub memory_budget: Option<u64>,
+}
+
+impl CompilerJobLimits {
+    /// Returns true if no limits are configured.
+    pub fn is_empty(&self) -> bool {
+        self.jobs.is_empty() && self.memory_budget.is_none()
+    }
+}
+
+/// Blocks parallel jobs until they can run within the [`CompilerJobLimits`].
+struct JobThrottle<'a> {
+    limits: &'a CompilerJobLimits,
+    state: Mutex<JobThrottleState>,
+    released: Condvar,
+}
+
+#[derive(Default)]
+struct JobThrottleState {
+    /// Number of running jobs per compiler.
+    running: HashMap<String, usize>,
+    /// Estimated memory of all running jobs.
+    memory: u64,
+}
+
+impl<'a> JobThrottle<'a> {
+    fn new(limits: &'a CompilerJobLimits) -> Self {
+        Self { limits, state: Default::default(), released: Condvar::new() }
+    }
+
+    /// Waits until a job of the given compiler can be started.
+    fn acquire(&self, compiler: &str) -> Option<JobPermit<'_, 'a>> {
+        if self.limits.is_empty() {
+            return None;
+        }
+
+        let memory = self.limits.job_memory.get(compiler).copied().unwrap_or_default();
+        let mut state = self.state.lock().unwrap();
+        loop {
+            let running = state.running.get(compiler).copied().unwrap_or_default();
+            let jobs_available = self.limits.jobs.get(compiler).is_none_or(|max| running < *max);
+            let memory_available = self.limits.memory_budget.is_none_or(|budget| {
+                memory == 0 || state.memory == 0 || state.memory + memory <= budget
+            });
+            if jobs_available && memory_available {
+                break;
+            }
+            trace!(compiler, running, memory = state.memory, "waiting for compiler job limits");
+            state = self.released.wait(state).unwrap();
+        }
+
+        *state.running.entry(compiler.to_string()).or_default() += 1;
+        state.memory += memory;
+        Some(JobPermit { throttle: self, compiler: compiler.to_string(), memory })
+    }
+}
+
+/// A running job, releasing its limits when dropped.
+struct JobPermit<'t, 'a> {
+    throttle: &'t JobThrottle<'a>,
+    compiler: String,
+    memory: u64,
+}
+
+impl Drop for JobPermit<'_, '_> {
+    fn drop(&mut self) {
+        let mut state = self.throttle.state.lock().unwrap();
+        if let Some(running) = state.running.get_mut(&self.compiler) {
+            *running -= 1;
+        }
+        state.memory -= self.memory;
+        self.throttle.released.notify_all();
+    }
+}
+
 fn compound_version(mut compiler_version: Version, input_version: &Version) -> Version {
     if compiler_version != *input_version {
         let build = if compiler_version.build.is_empty() {
@@ -900,4 +1009,56 @@ contract B {}
         compiled.assert_success();
         assert_eq!(compiled.artifacts().count(), 2);
     }
+
+    /// Runs the jobs on parallel threads and returns the maximum number of jobs running at once.
+    fn max_parallel_jobs(limits: &CompilerJobLimits, compilers: &[&str]) -> usize {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let throttle = JobThrottle::new(limits);
+        let running = AtomicUsize::new(0);
+        let max = AtomicUsize::new(0);
+        std::thread::scope(|scope| {
+            for compiler in compilers {
+                scope.spawn(|| {
+                    let _permit = throttle.acquire(compiler);
+                    let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    max.fetch_max(now, Ordering::SeqCst);
+                    std::thread::sleep(std::time::Duration::from_millis(50));
+                    running.fetch_sub(1, Ordering::SeqCst);
+                });
+            }
+        });
+        max.into_inner()
+    }
+
+    #[test]
+    fn can_limit_compiler_jobs() {
+        let limits = CompilerJobLimits {
+            jobs: BTreeMap::from([("resolc".to_string(), 2)]),
+            ..Default::default()
+        };
+        assert_eq!(max_parallel_jobs(&limits, &["resolc"; 6]), 2);
+        assert!(max_parallel_jobs(&limits, &["solc"; 4]) > 2);
+
+        let limits = CompilerJobLimits {
+            job_memory: BTreeMap::from([("resolc".to_string(), 60), ("huge".to_string(), 200)]),
+            memory_budget: Some(100),
+            ..Default::default()
+        };
+        assert_eq!(max_parallel_jobs(&limits, &["resolc"; 4]), 1);
+        // a job exceeding the budget still runs
+        assert_eq!(max_parallel_jobs(&limits, &["huge"; 2]), 1);
+        // jobs without a memory estimate aren't throttled
+        assert!(max_parallel_jobs(&limits, &["solc"; 4]) > 1);
+    }
+
+    #[test]
+    fn compound_version_tracks_compiler_version() {
+        let solc = Version::new(0, 8, 28);
+        assert_eq!(compound_version(solc.clone(), &solc), solc);
+
+        let resolc = compound_version(Version::parse("0.1.0-dev.13").unwrap(), &solc);
+        assert_eq!(resolc.to_string(), "0.1.0-dev.13+0.8.28");
+        assert_ne!(resolc, compound_version(Version::parse("0.1.0-dev.14").unwrap(), &solc));
+    }
 }
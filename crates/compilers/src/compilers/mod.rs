@@ -1,9 +1,9 @@
-use crate::ProjectPathsConfig;
+use crate::{buildinfo::ToolchainVersions, ProjectPathsConfig};
 use alloy_json_abi::JsonAbi;
 use core::fmt;
 use foundry_compilers_artifacts::{
     error::SourceLocation,
-    output_selection::OutputSelection,
+    output_selection::{ContractOutputSelection, OutputSelection},
     remappings::Remapping,
     sources::{Source, Sources},
     BytecodeObject, CompactContractRef, Contract, FileToContractsMap, Severity, SourceFile,
@@ -66,6 +66,120 @@ impl fmt::Display for CompilerVersion {
     }
 }
 
+/// The virtual machine a compiler emits code for.
+///
+/// Projects whose compiler supports multiple targets compile every source once per target, see
+/// [`Compiler::targets`].
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum CompilationTarget {
+    /// EVM bytecode.
+    #[default]
+    Evm,
+    /// PolkaVM blobs, compiled by `resolc`.
+    PolkaVm,
+}
+
+impl CompilationTarget {
+    /// Returns true if this is the EVM target.
+    pub fn is_evm(&self) -> bool {
+        matches!(self, Self::Evm)
+    }
+}
+
+impl fmt::Display for CompilationTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Evm => f.write_str("evm"),
+            Self::PolkaVm => f.write_str("polkavm"),
+        }
+    }
+}
+
+/// The outputs a compiler can emit, see [`Compiler::output_capabilities`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OutputCapabilities {
+    /// The contract level outputs that can be requested, or `None` if every output can be
+    /// requested.
+    pub selectable: Option<&'static [ContractOutputSelection]>,
+    /// The contract level outputs that are always emitted and therefore must not be requested.
+    pub always_emitted: &'static [ContractOutputSelection],
+    /// Whether the file level `ast` output can be requested.
+    pub ast: bool,
+}
+
+impl OutputCapabilities {
+    /// The capabilities of a compiler supporting every output.
+    pub const ALL: Self = Self { selectable: None, always_emitted: &[], ast: true };
+
+    /// Returns true if the output can be requested from the compiler.
+    pub fn is_selectable(&self, output: &ContractOutputSelection) -> bool {
+        self.selectable.is_none_or(|outputs| outputs.contains(output))
+    }
+
+    /// Returns true if the compiler emits the output, either when requested or always.
+    pub fn is_supported(&self, output: &ContractOutputSelection) -> bool {
+        self.is_selectable(output) || self.always_emitted.contains(output)
+    }
+
+    /// Returns all outputs the compiler doesn't emit.
+    pub fn unsupported<'a>(
+        &self,
+        outputs: impl IntoIterator<Item = &'a ContractOutputSelection>,
+    ) -> Vec<ContractOutputSelection> {
+        outputs.into_iter().filter(|output| !self.is_supported(output)).copied().collect()
+    }
+
+    /// Removes all outputs that can't be requested from the output selection and normalizes the
+    /// remaining ones, e.g. `asm` becomes `evm.assembly`.
+    ///
+    /// Returns the removed outputs the compiler doesn't emit at all, i.e. the outputs the caller
+    /// won't get. Outputs that are always emitted are removed silently, and so are the
+    /// [`ContractOutputSelection::basic`] outputs, which are requested by default rather than by
+    /// the user.
+    pub fn sanitize(&self, selection: &mut OutputSelection) -> BTreeSet<String> {
+        let mut dropped = BTreeSet::new();
+        if self.selectable.is_none() && self.ast {
+            return dropped;
+        }
+
+        let defaults = ContractOutputSelection::basic();
+
+        for contracts in selection.0.values_mut() {
+            for outputs in contracts.values_mut() {
+                *outputs = std::mem::take(outputs)
+                    .into_iter()
+                    .filter_map(|output| {
+                        if output == "ast" {
+                            if !self.ast {
+                                dropped.insert(output);
+                                return None;
+                            }
+                            return Some(output);
+                        }
+                        match output.parse::<ContractOutputSelection>() {
+                            Ok(parsed) if self.is_selectable(&parsed) => Some(parsed.to_string()),
+                            Ok(parsed)
+                                if self.always_emitted.contains(&parsed)
+                                    || defaults.contains(&parsed) =>
+                            {
+                                None
+                            }
+                            _ => {
+                                dropped.insert(output);
+                                None
+                            }
+                        }
+                    })
+                    .collect();
+            }
+        }
+        dropped
+    }
+}
+
 /// Compilation settings including evm_version, output_selection, etc.
 pub trait CompilerSettings:
     Default + Serialize + DeserializeOwned + Clone + Debug + Send + Sync + 'static
@@ -84,6 +198,15 @@ pub trait CompilerSettings:
     /// to be a subset of `cached.output_selection`.
     fn can_use_cached(&self, other: &Self) -> bool;
 
+    /// Returns true if the artifacts compiled for the given `target` with `other` config can be
+    /// reused with this config.
+    ///
+    /// Invoked for every cached artifact if [`Self::can_use_cached`] fails, which allows settings
+    /// that are only relevant to some compilers to invalidate only their artifacts.
+    fn can_use_cached_target(&self, other: &Self, _target: CompilationTarget) -> bool {
+        self.can_use_cached(other)
+    }
+
     /// Method which might be invoked to add remappings to the input.
     fn with_remappings(self, _remappings: &[Remapping]) -> Self {
         self
@@ -109,6 +232,17 @@ pub trait CompilerSettings:
 
     /// Returns whether current settings satisfy given restrictions.
     fn satisfies_restrictions(&self, restrictions: &Self::Restrictions) -> bool;
+
+    /// Returns the target these settings compile for, if the compiler supports multiple
+    /// [`Compiler::targets`].
+    fn target(&self) -> CompilationTarget {
+        CompilationTarget::Evm
+    }
+
+    /// Builder method to set the target to compile for, see [`Self::target`].
+    fn with_target(self, _target: CompilationTarget) -> Self {
+        self
+    }
 }
 
 /// Input of a compiler, including sources and settings used for their compilation.
@@ -151,6 +285,14 @@ pub trait ParsedSource: Debug + Sized + Send + Clone {
     /// Returns the version requirement of the source.
     fn version_req(&self) -> Option<&VersionReq>;
 
+    /// Returns the `resolc` version requirement of the source.
+    ///
+    /// Sources are only compiled together if they and their imports require the same `resolc`
+    /// versions, see [`Resolc`](crate::resolc::Resolc).
+    fn resolc_version_req(&self) -> Option<&VersionReq> {
+        None
+    }
+
     /// Returns a list of contract names defined in the source.
     fn contract_names(&self) -> &[String];
 
@@ -209,6 +351,9 @@ pub struct CompilerOutput<E, C> {
     pub sources: BTreeMap<PathBuf, SourceFile>,
     #[serde(default, skip_serializing_if = "::std::collections::BTreeMap::is_empty")]
     pub metadata: BTreeMap<String, serde_json::Value>,
+    /// Versions of the tools behind the compiler, recorded in the build info.
+    #[serde(skip)]
+    pub toolchain: ToolchainVersions,
 }
 
 impl<E, C> CompilerOutput<E, C> {
@@ -251,6 +396,7 @@ impl<E, C> CompilerOutput<E, C> {
             contracts: self.contracts,
             sources: self.sources,
             metadata: self.metadata,
+            toolchain: self.toolchain,
         }
     }
 }
@@ -262,6 +408,7 @@ impl<E, C> Default for CompilerOutput<E, C> {
             contracts: BTreeMap::new(),
             sources: BTreeMap::new(),
             metadata: BTreeMap::new(),
+            toolchain: ToolchainVersions::default(),
         }
     }
 }
@@ -338,9 +485,35 @@ pub trait Compiler: Send + Sync + Clone {
     /// Returns compiler name used by reporters to display output during compilation.
     fn compiler_name(&self, input: &Self::Input) -> Cow<'static, str>;
 
+    /// Returns the stable identifier of the compiler invoked for the given input, which the
+    /// [`CompilerJobLimits`](crate::project::CompilerJobLimits) are configured for.
+    ///
+    /// Defaults to the [`Self::compiler_name`].
+    fn job_key(&self, input: &Self::Input) -> Cow<'static, str> {
+        self.compiler_name(input)
+    }
+
     /// Returns compiler version used by reporters to display output during compilation.
     fn compiler_version(&self, input: &Self::Input) -> Version;
 
+    /// Returns the version of the `resolc` binary compiling sources of the given language and
+    /// version with the settings and the combined `resolc` version requirement of the sources.
+    ///
+    /// If no installed binary satisfies the requirement, a matching one is installed unless
+    /// `offline`. Sources resolving to the same binary are compiled together.
+    ///
+    /// Returns `None` if the sources aren't compiled with `resolc`.
+    fn resolc_version(
+        &self,
+        _settings: &Self::Settings,
+        _language: &Self::Language,
+        _version: &Version,
+        _req: Option<&VersionReq>,
+        _offline: bool,
+    ) -> Result<Option<Version>> {
+        Ok(None)
+    }
+
     /// Main entrypoint for the compiler. Compiles given input into [CompilerOutput]. Takes
     /// ownership over the input and returns back version with potential modifications made to it.
     /// Returned input is always the one which was seen by the binary.
@@ -352,6 +525,23 @@ pub trait Compiler: Send + Sync + Clone {
     /// Returns all versions available locally and remotely. Should return versions with stripped
     /// metadata.
     fn available_versions(&self, language: &Self::Language) -> Vec<CompilerVersion>;
+
+    /// Returns the targets the compiler emits code for.
+    ///
+    /// If there are multiple targets, the first one is the primary target and every source is
+    /// additionally compiled for the other targets, using the settings profiles derived by
+    /// [`Project::add_target_profiles`](crate::Project::add_target_profiles).
+    fn targets(&self) -> Vec<CompilationTarget> {
+        vec![CompilationTarget::Evm]
+    }
+
+    /// Returns the outputs the compiler can emit for sources of the given language.
+    ///
+    /// Requested outputs the compiler doesn't support are removed from the input and reported via
+    /// [`Reporter::on_unsupported_outputs`](crate::report::Reporter::on_unsupported_outputs).
+    fn output_capabilities(&self, _language: &Self::Language) -> OutputCapabilities {
+        OutputCapabilities::ALL
+    }
 }
 
 pub(crate) fn cache_version(
@@ -6,8 +6,8 @@ use super::{
         input::VyperVersionedInput, parser::VyperParsedSource, Vyper, VyperLanguage,
         VYPER_EXTENSIONS,
     },
-    CompilationError, Compiler, CompilerInput, CompilerOutput, CompilerSettings, CompilerVersion,
-    Language, ParsedSource, SimpleCompilerName,
+    CompilationError, CompilationTarget, Compiler, CompilerInput, CompilerOutput, CompilerSettings,
+    CompilerVersion, Language, OutputCapabilities, ParsedSource, SimpleCompilerName,
 };
 use crate::{
     artifacts::vyper::{VyperCompilationError, VyperSettings},
@@ -23,7 +23,7 @@ use foundry_compilers_artifacts::{
     Contract, Error, Severity, SolcLanguage,
 };
 use foundry_compilers_core::error::{Result, SolcError};
-use semver::Version;
+use semver::{Version, VersionReq};
 use serde::{Deserialize, Serialize};
 use std::{
     borrow::Cow,
@@ -44,6 +44,14 @@ pub struct MultiCompiler {
 pub enum SolidityCompiler {
     Solc(SolcCompiler),
     Resolc(Resolc),
+    /// Compiles every source to both EVM bytecode, using the `solc` of the [`Resolc`] compiler,
+    /// and PolkaVM blobs.
+    ///
+    /// The target of an input is determined by the
+    /// [`target`](crate::resolc::ResolcSettings::target) of its settings profile. The PolkaVM
+    /// profiles are derived from the EVM ones, see
+    /// [`Project::add_target_profiles`](crate::Project::add_target_profiles).
+    Dual(Resolc),
     #[default]
     MissingInstallation,
 }
@@ -172,6 +180,11 @@ impl CompilerSettings for MultiCompilerSettings {
         self.solc.can_use_cached(&other.solc) && self.vyper.can_use_cached(&other.vyper)
     }
 
+    fn can_use_cached_target(&self, other: &Self, target: CompilationTarget) -> bool {
+        self.solc.can_use_cached_target(&other.solc, target)
+            && self.vyper.can_use_cached_target(&other.vyper, target)
+    }
+
     fn update_output_selection(&mut self, f: impl FnOnce(&mut OutputSelection) + Copy) {
         self.solc.update_output_selection(f);
         self.vyper.update_output_selection(f);
@@ -209,6 +222,14 @@ impl CompilerSettings for MultiCompilerSettings {
         self.solc.satisfies_restrictions(&restrictions.solc)
             && self.vyper.satisfies_restrictions(&restrictions.vyper)
     }
+
+    fn target(&self) -> CompilationTarget {
+        self.solc.target()
+    }
+
+    fn with_target(self, target: CompilationTarget) -> Self {
+        Self { solc: self.solc.with_target(target), ..self }
+    }
 }
 
 impl From<MultiCompilerSettings> for SolcSettings {
@@ -294,20 +315,11 @@ impl Compiler for MultiCompiler {
         match input {
             MultiCompilerInput::Solc(sol) => match &self.solidity {
                 SolidityCompiler::Solc(solc) => solc.compiler_version(sol),
-                SolidityCompiler::Resolc(r) => {
-                    let input = sol.clone();
-                    let input = ResolcVersionedInput::build(
-                        input.input.sources,
-                        SolcSettings {
-                            settings: input.input.settings,
-                            cli_settings: input.cli_settings,
-                            extra_settings: input.extra_settings,
-                        },
-                        input.input.language,
-                        input.version,
-                    );
-                    r.compiler_version(&input)
-                }
+                SolidityCompiler::Resolc(r) => r.compiler_version(&resolc_input(sol)),
+                SolidityCompiler::Dual(r) => match sol.extra_settings.target {
+                    CompilationTarget::Evm => r.solc.compiler_version(sol),
+                    CompilationTarget::PolkaVm => r.compiler_version(&resolc_input(sol)),
+                },
                 SolidityCompiler::MissingInstallation => sol.version().clone(),
             },
             MultiCompilerInput::Vyper(v) => self
@@ -318,17 +330,56 @@ impl Compiler for MultiCompiler {
         }
     }
 
+    fn resolc_version(
+        &self,
+        settings: &Self::Settings,
+        language: &Self::Language,
+        version: &Version,
+        req: Option<&VersionReq>,
+        offline: bool,
+    ) -> Result<Option<Version>> {
+        let MultiCompilerLanguage::Solc(language) = language else { return Ok(None) };
+        match &self.solidity {
+            SolidityCompiler::Resolc(r) => {
+                r.resolc_version(&settings.solc, language, version, req, offline)
+            }
+            SolidityCompiler::Dual(r) if settings.target() == CompilationTarget::PolkaVm => {
+                r.resolc_version(&settings.solc, language, version, req, offline)
+            }
+            _ => Ok(None),
+        }
+    }
+
     fn compiler_name(&self, input: &Self::Input) -> Cow<'static, str> {
         match input {
-            MultiCompilerInput::Solc(_) => match &self.solidity {
+            MultiCompilerInput::Solc(sol) => match &self.solidity {
                 SolidityCompiler::Solc(_) => SolcCompiler::compiler_name_default(),
                 SolidityCompiler::Resolc(_) => Resolc::compiler_name_default(),
+                SolidityCompiler::Dual(_) => match sol.extra_settings.target {
+                    CompilationTarget::Evm => SolcCompiler::compiler_name_default(),
+                    CompilationTarget::PolkaVm => Resolc::compiler_name_default(),
+                },
                 SolidityCompiler::MissingInstallation => "No applicable compilers installed".into(),
             },
             MultiCompilerInput::Vyper(_) => Vyper::compiler_name_default(),
         }
     }
 
+    fn job_key(&self, input: &Self::Input) -> Cow<'static, str> {
+        match input {
+            MultiCompilerInput::Solc(sol) => match &self.solidity {
+                SolidityCompiler::Resolc(_) => Resolc::JOB_KEY.into(),
+                SolidityCompiler::Dual(_)
+                    if sol.extra_settings.target == CompilationTarget::PolkaVm =>
+                {
+                    Resolc::JOB_KEY.into()
+                }
+                _ => SolcCompiler::JOB_KEY.into(),
+            },
+            MultiCompilerInput::Vyper(_) => Vyper::JOB_KEY.into(),
+        }
+    }
+
     fn compile(
         &self,
         input: &Self::Input,
@@ -337,21 +388,13 @@ impl Compiler for MultiCompiler {
             MultiCompilerInput::Solc(input) => match &self.solidity {
                 SolidityCompiler::Solc(solc_compiler) => Compiler::compile(solc_compiler, input)
                     .map(|res| res.map_err(MultiCompilerError::Solc)),
-                SolidityCompiler::Resolc(resolc) => {
-                    let input = input.clone();
-                    let input = ResolcVersionedInput::build(
-                        input.input.sources,
-                        SolcSettings {
-                            settings: input.input.settings,
-                            cli_settings: input.cli_settings,
-                            extra_settings: input.extra_settings,
-                        },
-                        input.input.language,
-                        input.version,
-                    );
-                    Compiler::compile(resolc, &input)
-                        .map(|res| res.map_err(MultiCompilerError::Solc))
+                SolidityCompiler::Resolc(resolc) => Compiler::compile(resolc, &resolc_input(input))
+                    .map(|res| res.map_err(MultiCompilerError::Solc)),
+                SolidityCompiler::Dual(resolc) => match input.extra_settings.target {
+                    CompilationTarget::Evm => Compiler::compile(&resolc.solc, input),
+                    CompilationTarget::PolkaVm => Compiler::compile(resolc, &resolc_input(input)),
                 }
+                .map(|res| res.map_err(MultiCompilerError::Solc)),
                 SolidityCompiler::MissingInstallation => {
                     Err(SolcError::msg("No solidity compiler is available"))
                 }
@@ -371,7 +414,10 @@ impl Compiler for MultiCompiler {
         match language {
             MultiCompilerLanguage::Solc(language) => match &self.solidity {
                 SolidityCompiler::Solc(solc_compiler) => solc_compiler.available_versions(language),
-                SolidityCompiler::Resolc(resolc) => resolc.available_versions(language),
+                // both targets share the `solc` version, so it must be supported by `resolc`
+                SolidityCompiler::Resolc(resolc) | SolidityCompiler::Dual(resolc) => {
+                    resolc.available_versions(language)
+                }
                 SolidityCompiler::MissingInstallation => Default::default(),
             },
             MultiCompilerLanguage::Vyper(language) => {
@@ -379,6 +425,31 @@ impl Compiler for MultiCompiler {
             }
         }
     }
+
+    fn output_capabilities(&self, language: &Self::Language) -> OutputCapabilities {
+        match (language, &self.solidity) {
+            (
+                MultiCompilerLanguage::Solc(_),
+                SolidityCompiler::Resolc(_) | SolidityCompiler::Dual(_),
+            ) => Resolc::OUTPUT_CAPABILITIES,
+            _ => OutputCapabilities::ALL,
+        }
+    }
+
+    fn targets(&self) -> Vec<CompilationTarget> {
+        match &self.solidity {
+            SolidityCompiler::Resolc(_) => vec![CompilationTarget::PolkaVm],
+            SolidityCompiler::Dual(_) => vec![CompilationTarget::Evm, CompilationTarget::PolkaVm],
+            SolidityCompiler::Solc(_) | SolidityCompiler::MissingInstallation => {
+                vec![CompilationTarget::Evm]
+            }
+        }
+    }
+}
+
+/// Converts the `solc` input to the input of the [`Resolc`] compiler.
+fn resolc_input(input: &SolcVersionedInput) -> ResolcVersionedInput {
+    input.clone().into()
 }
 
 impl ParsedSource for MultiCompilerParsedSource {
@@ -398,13 +469,20 @@ impl ParsedSource for MultiCompilerParsedSource {
         }
     }
 
-    fn version_req(&self) -> Option<&semver::VersionReq> {
+    fn version_req(&self) -> Option<&VersionReq> {
         match self {
             Self::Solc(parsed) => parsed.version_req(),
             Self::Vyper(parsed) => parsed.version_req(),
         }
     }
 
+    fn resolc_version_req(&self) -> Option<&VersionReq> {
+        match self {
+            Self::Solc(parsed) => parsed.resolc_version_req(),
+            Self::Vyper(_) => None,
+        }
+    }
+
     fn contract_names(&self) -> &[String] {
         match self {
             Self::Solc(parsed) => parsed.contract_names(),
@@ -1,22 +1,39 @@
 use crate::{
     error::{Result, SolcError},
+    report,
     resolver::parse::SolData,
     solc::{Solc, SolcCompiler, SolcSettings},
-    Compiler, CompilerVersion, SimpleCompilerName,
+    Compiler, CompilerContract, CompilerOutput, CompilerVersion, OutputCapabilities,
+    SimpleCompilerName,
+};
+use alloy_primitives::{hex, Bytes};
+use foundry_compilers_artifacts::{
+    output_selection::{
+        BytecodeOutputSelection, ContractOutputSelection, DeployedBytecodeOutputSelection,
+        EvmOutputSelection,
+    },
+    resolc::{
+        contract::ResolcContract, sourcemap::polkavm_source_map, ResolcCompilerOutput, ResolcEVM,
+        STDERR_WARNING_ERROR_CODE,
+    },
+    solc::error::SourceLocation,
+    BytecodeObject, Contract, Error, Severity, SolcLanguage, Source, SourceFile, Sources,
 };
-use foundry_compilers_artifacts::{resolc::ResolcCompilerOutput, Contract, Error, SolcLanguage};
 use itertools::Itertools;
 use rvm::Binary;
 use semver::{BuildMetadata, Comparator, Prerelease, Version, VersionReq};
 use serde::Serialize;
 use std::{
-    io::{self, Write},
+    borrow::Cow,
+    collections::HashMap,
+    io::{self, Read, Write},
     path::{Path, PathBuf},
     process::{Child, Command, Output, Stdio},
     str::FromStr,
+    sync::{Mutex, OnceLock},
 };
 
-use super::{ResolcInput, ResolcVersionedInput};
+use super::{input::combine_resolc_version_reqs, ResolcInput, ResolcLocator, ResolcVersionedInput};
 
 #[derive(Clone, Debug)]
 pub struct Resolc {
@@ -24,6 +41,8 @@ pub struct Resolc {
     pub resolc_version: Version,
     pub supported_solc_versions: semver::VersionReq,
     pub solc: SolcCompiler,
+    /// Locates binaries not managed by `rvm` when the input requires a different version.
+    pub locator: ResolcLocator,
 }
 
 impl Compiler for Resolc {
@@ -34,14 +53,41 @@ impl Compiler for Resolc {
     type Settings = SolcSettings;
     type Language = SolcLanguage;
 
-    fn compiler_version(&self, _input: &Self::Input) -> Version {
-        self.resolc_version.clone()
+    /// Returns the version of the `resolc` compiler the input is compiled with, see
+    /// [`Resolc::for_input`].
+    ///
+    /// This only looks up the binary, which is installed if needed when the sources are grouped
+    /// by version, see [`Compiler::resolc_version`]. The configured version is returned if no
+    /// binary satisfies the input.
+    fn compiler_version(&self, input: &Self::Input) -> Version {
+        match self.find_for_input(input) {
+            Ok(Some(resolc)) => resolc.resolc_version.clone(),
+            _ => self.resolc_version.clone(),
+        }
+    }
+
+    fn resolc_version(
+        &self,
+        settings: &Self::Settings,
+        _language: &SolcLanguage,
+        version: &Version,
+        req: Option<&VersionReq>,
+        offline: bool,
+    ) -> Result<Option<Version>> {
+        let req =
+            combine_resolc_version_reqs(settings.extra_settings.resolc_version.iter().chain(req));
+        let resolc = self.resolve(req.as_ref(), version, offline)?;
+        Ok(Some(resolc.resolc_version.clone()))
     }
 
     fn compiler_name(&self, _input: &Self::Input) -> std::borrow::Cow<'static, str> {
         Self::compiler_name_default()
     }
 
+    fn job_key(&self, _input: &Self::Input) -> std::borrow::Cow<'static, str> {
+        Self::JOB_KEY.into()
+    }
+
     /// Instead of using specific sols version we are going to autodetect
     /// Installed versions
     fn available_versions(&self, language: &SolcLanguage) -> Vec<CompilerVersion> {
@@ -60,16 +106,17 @@ impl Compiler for Resolc {
         &self,
         input: &Self::Input,
     ) -> Result<crate::compilers::CompilerOutput<Error, Self::CompilerContract>, SolcError> {
-        let mut solc = self.solc(input)?;
-        solc.base_path.clone_from(&input.cli_settings.base_path);
-        solc.allow_paths.clone_from(&input.cli_settings.allow_paths);
-        solc.include_paths.clone_from(&input.cli_settings.include_paths);
-        solc.extra_args.extend_from_slice(&input.cli_settings.extra_args);
-        let results = self.compile_output::<ResolcInput>(&solc, &input.input)?;
-        let output = std::str::from_utf8(&results).map_err(|_| SolcError::InvalidUtf8)?;
-        let results: ResolcCompilerOutput =
-            serde_json::from_str(output).map_err(|e| SolcError::msg(e.to_string()))?;
-        Ok(results.into())
+        let resolc = self.for_input(input)?;
+        let solc = configure_solc(resolc.solc(input)?, input);
+        let results = match input.input.language {
+            SolcLanguage::Yul => resolc.compile_yul(&solc, &input.input)?,
+            _ => resolc.compile_standard_json(&solc, &input.input)?,
+        };
+        Ok(resolc.finish_output(input, results))
+    }
+
+    fn output_capabilities(&self, _language: &SolcLanguage) -> OutputCapabilities {
+        Self::OUTPUT_CAPABILITIES
     }
 }
 
@@ -80,6 +127,19 @@ impl SimpleCompilerName for Resolc {
     }
 }
 
+/// The configured binary, the `resolc` version requirement and the `solc` version of a version
+/// group.
+type ResolvedKey = (PathBuf, Option<VersionReq>, Version);
+
+/// Returns the `resolc` binaries resolved for version groups the configured binary doesn't
+/// satisfy, see [`Resolc::for_input`].
+///
+/// Entries are only returned while their binary exists.
+fn resolved_cache() -> std::sync::MutexGuard<'static, HashMap<ResolvedKey, Resolc>> {
+    static RESOLVED: OnceLock<Mutex<HashMap<ResolvedKey, Resolc>>> = OnceLock::new();
+    RESOLVED.get_or_init(Default::default).lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
 fn binary_compat_info(bin: &Binary) -> VersionReq {
     match &bin {
         Binary::Local { info, .. } | Binary::Remote(info) => semver::VersionReq {
@@ -104,6 +164,43 @@ fn binary_compat_info(bin: &Binary) -> VersionReq {
 }
 
 impl Resolc {
+    /// The [`Compiler::job_key`] of `resolc`.
+    pub const JOB_KEY: &'static str = "resolc";
+
+    /// The outputs `resolc` accepts in the output selection.
+    ///
+    /// Bytecode objects are always emitted, anything else like source maps or gas estimates is
+    /// not available for PolkaVM contracts.
+    pub const OUTPUT_CAPABILITIES: OutputCapabilities = OutputCapabilities {
+        selectable: Some(&[
+            ContractOutputSelection::Abi,
+            ContractOutputSelection::Metadata,
+            ContractOutputSelection::DevDoc,
+            ContractOutputSelection::UserDoc,
+            ContractOutputSelection::StorageLayout,
+            ContractOutputSelection::Ir,
+            ContractOutputSelection::IrOptimized,
+            ContractOutputSelection::Evm(EvmOutputSelection::MethodIdentifiers),
+            ContractOutputSelection::Evm(EvmOutputSelection::Assembly),
+            ContractOutputSelection::Evm(EvmOutputSelection::LegacyAssembly),
+            ContractOutputSelection::Evm(EvmOutputSelection::ByteCode(
+                BytecodeOutputSelection::All,
+            )),
+            ContractOutputSelection::Evm(EvmOutputSelection::DeployedByteCode(
+                DeployedBytecodeOutputSelection::All,
+            )),
+        ]),
+        always_emitted: &[
+            ContractOutputSelection::Evm(EvmOutputSelection::ByteCode(
+                BytecodeOutputSelection::Object,
+            )),
+            ContractOutputSelection::Evm(EvmOutputSelection::DeployedByteCode(
+                DeployedBytecodeOutputSelection::Object,
+            )),
+        ],
+        ast: true,
+    };
+
     pub fn new(resolc_path: impl Into<PathBuf>, solc_compiler: SolcCompiler) -> Result<Self> {
         let resolc_path = resolc_path.into();
         let resolc_version = Self::get_version_for_path(&resolc_path)?;
@@ -113,12 +210,35 @@ impl Resolc {
             resolc: resolc_path,
             solc: solc_compiler,
             supported_solc_versions,
+            locator: ResolcLocator::default(),
         })
     }
 
+    /// Sets the locator used to find binaries not managed by `rvm`.
+    #[must_use]
+    pub fn with_locator(mut self, locator: ResolcLocator) -> Self {
+        self.locator = locator;
+        self
+    }
+
+    /// Returns the installed `resolc` binary with the given version.
+    ///
+    /// Binaries managed by `rvm` take precedence, otherwise the default [`ResolcLocator`] is used.
     pub fn find_installed(
         resolc_version: &Version,
         solc_compiler: SolcCompiler,
+    ) -> Result<Option<Self>> {
+        Self::find_installed_with(ResolcLocator::default(), resolc_version, solc_compiler)
+    }
+
+    /// Returns the installed `resolc` binary with the given version, see [`Self::find_installed`].
+    ///
+    /// Binaries not managed by `rvm` are searched with the given locator, which is also used by
+    /// the returned compiler.
+    pub fn find_installed_with(
+        locator: ResolcLocator,
+        resolc_version: &Version,
+        solc_compiler: SolcCompiler,
     ) -> Result<Option<Self>> {
         let solc_version = match &solc_compiler {
             SolcCompiler::Specific(solc) => Some(solc.version_short()),
@@ -127,20 +247,34 @@ impl Resolc {
         };
         let bin =
             rvm::VersionManager::new(true).and_then(|vm| vm.get(resolc_version, solc_version));
-        match bin {
-            Ok(bin) => bin
-                .local()
-                .map(|path| {
-                    Ok(Self {
-                        resolc_version: bin.version().to_owned(),
-                        resolc: path.to_owned(),
-                        solc: solc_compiler,
-                        supported_solc_versions: binary_compat_info(&bin),
+        let err = match bin {
+            Ok(bin) => {
+                return bin
+                    .local()
+                    .map(|path| {
+                        Ok(Self {
+                            resolc_version: bin.version().to_owned(),
+                            resolc: path.to_owned(),
+                            solc: solc_compiler,
+                            supported_solc_versions: binary_compat_info(&bin),
+                            locator,
+                        })
                     })
-                })
-                .transpose(),
-            Err(rvm::Error::UnknownVersion { .. } | rvm::Error::NotInstalled { .. }) => Ok(None),
-            Err(e) => Err(SolcError::Message(e.to_string())),
+                    .transpose()
+            }
+            Err(err) => err,
+        };
+
+        // not managed by `rvm`, fall back to binaries installed elsewhere
+        if let Some(resolc) = locator.find(resolc_version, solc_compiler) {
+            return Ok(Some(resolc.with_locator(locator)));
+        }
+
+        match err {
+            rvm::Error::UnknownVersion { .. }
+            | rvm::Error::NotInstalled { .. }
+            | rvm::Error::NoVersionsInstalled => Ok(None),
+            e => Err(SolcError::Message(e.to_string())),
         }
     }
 
@@ -162,55 +296,244 @@ impl Resolc {
     }
 
     #[cfg(any(feature = "async", feature = "svm-solc"))]
-    pub fn install(_resolc_version: Option<&Version>, solc_compiler: SolcCompiler) -> Result<Self> {
-        let _solc_version = match &solc_compiler {
+    pub fn install(resolc_version: Option<&Version>, solc_compiler: SolcCompiler) -> Result<Self> {
+        foundry_compilers_core::utils::RuntimeOrHandle::new().block_on(async {
+            // rvm uses reqwest::blocking internally and so it's needed to wrap the calls in
+            // tokio::task::block_on or spawn_blocking to avoid panics Refer to: https://github.com/seanmonstar/reqwest/issues/1017
+            tokio::task::block_in_place(|| Self::blocking_install(resolc_version, solc_compiler))
+        })
+    }
+
+    /// Installs `resolc` like [`Self::install`], without blocking the runtime.
+    ///
+    /// `rvm` is run on the blocking thread pool, so unlike [`Self::install`] this also works on a
+    /// current thread runtime.
+    #[cfg(feature = "async")]
+    pub async fn async_install(
+        resolc_version: Option<&Version>,
+        solc_compiler: SolcCompiler,
+    ) -> Result<Self> {
+        let resolc_version = resolc_version.cloned();
+        tokio::task::spawn_blocking(move || {
+            Self::blocking_install(resolc_version.as_ref(), solc_compiler)
+        })
+        .await
+        .map_err(|err| SolcError::msg(format!("failed to install `resolc`: {err}")))?
+    }
+
+    /// Installs the given version, or the latest version supporting the `solc` compiler.
+    ///
+    /// Must not be called from an async context, see [`Self::install`].
+    #[cfg(any(feature = "async", feature = "svm-solc"))]
+    fn blocking_install(
+        resolc_version: Option<&Version>,
+        solc_compiler: SolcCompiler,
+    ) -> Result<Self> {
+        let solc_version = match &solc_compiler {
             SolcCompiler::Specific(solc) => Some(solc.version_short()),
             #[cfg(feature = "svm-solc")]
             SolcCompiler::AutoDetect => None,
         };
-        foundry_compilers_core::utils::RuntimeOrHandle::new().block_on(async {
-            // rvm uses reqwest::blocking internally and so it's needed to wrap the calls in
-            // tokio::task::block_on or spawn_blocking to avoid panics Refer to: https://github.com/seanmonstar/reqwest/issues/1017
-            tokio::task::block_in_place(|| {
-                let version_manager = rvm::VersionManager::new(false)
+        let version_manager =
+            rvm::VersionManager::new(false).map_err(|e| SolcError::Message(e.to_string()))?;
+
+        // Always will end up being `Binary::Local { .. }`
+        let binary = {
+            if let Some(resolc_version) = resolc_version {
+                version_manager
+                    .get_or_install(resolc_version, solc_version)
+                    .map_err(|e| SolcError::Message(e.to_string()))?
+            } else {
+                let versions = version_manager
+                    .list_available(solc_version.clone())
                     .map_err(|e| SolcError::Message(e.to_string()))?;
 
-                // Always will end up being `Binary::Local { .. }`
-                let binary = {
-                    if let Some(resolc_version) = _resolc_version {
-                        version_manager
-                            .get_or_install(resolc_version, _solc_version.clone())
-                            .map_err(|e| SolcError::Message(e.to_string()))?
-                    } else {
-                        let versions = version_manager
-                            .list_available(_solc_version.clone())
-                            .map_err(|e| SolcError::Message(e.to_string()))?;
-
-                        let Some(binary) = versions.into_iter().next_back() else {
-                            let message = "No `resolc` versions available.".to_string();
-                            return Err(SolcError::Message(message));
-                        };
-                        match binary {
-                            bin @ Binary::Local { .. } => bin,
-                            Binary::Remote(binary_info) => version_manager
-                                .get_or_install(&binary_info.version, _solc_version)
-                                .map_err(|e| SolcError::Message(e.to_string()))?,
-                        }
-                    }
+                let Some(binary) = versions.into_iter().next_back() else {
+                    let message = "No `resolc` versions available.".to_string();
+                    return Err(SolcError::Message(message));
                 };
+                match binary {
+                    bin @ Binary::Local { .. } => bin,
+                    Binary::Remote(binary_info) => version_manager
+                        .get_or_install(&binary_info.version, solc_version)
+                        .map_err(|e| SolcError::Message(e.to_string()))?,
+                }
+            }
+        };
 
-                let path = binary.local().expect("Can't happen to a local install");
+        let path = binary.local().expect("Can't happen to a local install");
 
-                Ok(Self {
-                    resolc_version: binary.version().to_owned(),
-                    resolc: path.to_owned(),
-                    solc: solc_compiler,
-                    supported_solc_versions: binary_compat_info(&binary),
-                })
-            })
+        Ok(Self {
+            resolc_version: binary.version().to_owned(),
+            resolc: path.to_owned(),
+            solc: solc_compiler,
+            supported_solc_versions: binary_compat_info(&binary),
+            locator: ResolcLocator::default(),
         })
     }
 
+    /// Installs the latest `resolc` release matching the requirement and supporting the `solc`
+    /// version.
+    ///
+    /// Must not be called from an async context, see [`Self::install`].
+    #[cfg(any(feature = "async", feature = "svm-solc"))]
+    fn blocking_install_matching(
+        req: &VersionReq,
+        solc_version: &Version,
+        solc_compiler: SolcCompiler,
+    ) -> Result<Self> {
+        let version_manager =
+            rvm::VersionManager::new(false).map_err(|e| SolcError::Message(e.to_string()))?;
+        let binary = version_manager
+            .list_available(Some(solc_version.clone()))
+            .map_err(|e| SolcError::Message(e.to_string()))?
+            .into_iter()
+            .rfind(|bin| {
+                req.matches(bin.version()) && binary_compat_info(bin).matches(solc_version)
+            })
+            .ok_or_else(|| no_matching_resolc(req, solc_version))?;
+        Self::blocking_install(Some(binary.version()), solc_compiler)
+    }
+
+    /// Returns the `resolc` compiler to compile the input with.
+    ///
+    /// The configured binary is used if it satisfies the
+    /// [`resolc_version_req`](ResolcVersionedInput::resolc_version_req) of the input. Otherwise the
+    /// latest installed `resolc` satisfying it and supporting the `solc` version of the input is
+    /// used. If there is none, the latest such release is installed via `rvm`.
+    ///
+    /// The result is cached for the `resolc` version requirement and the `solc` version, so the
+    /// binary is only resolved once for all inputs of a version group.
+    pub fn for_input(&self, input: &ResolcVersionedInput) -> Result<Cow<'_, Self>> {
+        self.resolve(input.resolc_version_req.as_ref(), &input.solc_version, false)
+    }
+
+    /// Returns the `resolc` compiler satisfying the version requirement and supporting the `solc`
+    /// version, see [`Self::for_input`].
+    ///
+    /// If no installed binary matches, one is installed unless `offline`.
+    pub fn resolve(
+        &self,
+        req: Option<&VersionReq>,
+        solc_version: &Version,
+        offline: bool,
+    ) -> Result<Cow<'_, Self>> {
+        if let Some(resolc) = self.find(req, solc_version)? {
+            return Ok(resolc);
+        }
+        let req = req.cloned().unwrap_or(VersionReq::STAR);
+        if offline {
+            return Err(SolcError::msg(format!(
+                "no installed `resolc` version matching \"{req}\" supports `solc` \
+                 v{solc_version}, and `resolc` can't be installed in offline mode"
+            )));
+        }
+
+        #[cfg(any(feature = "async", feature = "svm-solc"))]
+        {
+            let resolc = foundry_compilers_core::utils::RuntimeOrHandle::new().block_on(async {
+                // see `Self::install`
+                tokio::task::block_in_place(|| {
+                    Self::blocking_install_matching(&req, solc_version, self.solc.clone())
+                })
+            })?;
+            Ok(Cow::Owned(self.cache_resolved(Some(&req), solc_version, resolc)))
+        }
+        #[cfg(not(any(feature = "async", feature = "svm-solc")))]
+        {
+            Err(no_matching_resolc(&req, solc_version))
+        }
+    }
+
+    /// Returns the configured or an installed `resolc` satisfying the version requirement of the
+    /// input, or `None` if a matching version has to be installed.
+    pub(crate) fn find_for_input(
+        &self,
+        input: &ResolcVersionedInput,
+    ) -> Result<Option<Cow<'_, Self>>> {
+        self.find(input.resolc_version_req.as_ref(), &input.solc_version)
+    }
+
+    /// Returns the configured or an installed `resolc` satisfying the version requirement and
+    /// supporting the `solc` version, or `None` if a matching version has to be installed.
+    fn find(
+        &self,
+        req: Option<&VersionReq>,
+        solc_version: &Version,
+    ) -> Result<Option<Cow<'_, Self>>> {
+        let Some(req) = req else { return Ok(Some(Cow::Borrowed(self))) };
+        if req.matches(&self.resolc_version) {
+            return Ok(Some(Cow::Borrowed(self)));
+        }
+        let key = self.resolved_key(Some(req), solc_version);
+        {
+            let mut cache = resolved_cache();
+            match cache.get(&key) {
+                Some(resolc) if resolc.resolc.exists() => {
+                    return Ok(Some(Cow::Owned(resolc.clone())))
+                }
+                // the binary was removed since it was resolved
+                Some(_) => {
+                    cache.remove(&key);
+                }
+                None => {}
+            }
+        }
+        let is_compatible = |resolc: &Self| {
+            req.matches(&resolc.resolc_version)
+                && resolc.supported_solc_versions.matches(solc_version)
+        };
+
+        let installed = match rvm::VersionManager::new(true) {
+            Ok(vm) => vm
+                .list_available(Some(solc_version.clone()))
+                .map_err(|e| SolcError::Message(e.to_string()))?,
+            Err(rvm::Error::NoVersionsInstalled) => Vec::new(),
+            Err(e) => return Err(SolcError::Message(e.to_string())),
+        };
+        let managed = installed.iter().rev().find_map(|bin| {
+            let resolc = Self {
+                resolc_version: bin.version().to_owned(),
+                resolc: bin.local()?.to_owned(),
+                solc: self.solc.clone(),
+                supported_solc_versions: binary_compat_info(bin),
+                locator: self.locator.clone(),
+            };
+            is_compatible(&resolc).then_some(resolc)
+        });
+        if let Some(resolc) = managed {
+            return Ok(Some(Cow::Owned(self.cache_resolved(Some(req), solc_version, resolc))));
+        }
+
+        // not managed by `rvm`, fall back to binaries installed elsewhere
+        let resolc = self
+            .locator
+            .find_all(self.solc.clone())
+            .into_iter()
+            .filter(is_compatible)
+            .max_by(|a, b| a.resolc_version.cmp(&b.resolc_version));
+        Ok(resolc.map(|resolc| {
+            let resolc = resolc.with_locator(self.locator.clone());
+            Cow::Owned(self.cache_resolved(Some(req), solc_version, resolc))
+        }))
+    }
+
+    /// Returns the key of the binary resolved for the version group in the [`resolved_cache`].
+    fn resolved_key(&self, req: Option<&VersionReq>, solc_version: &Version) -> ResolvedKey {
+        (self.resolc.clone(), req.cloned(), solc_version.clone())
+    }
+
+    /// Caches the binary resolved for the version group and returns it.
+    fn cache_resolved(
+        &self,
+        req: Option<&VersionReq>,
+        solc_version: &Version,
+        resolc: Self,
+    ) -> Self {
+        resolved_cache().insert(self.resolved_key(req, solc_version), resolc.clone());
+        resolc
+    }
+
     fn supported_solc_versions(path: &Path) -> Result<semver::VersionReq> {
         let mut cmd = Command::new(path);
         cmd.arg("--supported-solc-versions")
@@ -238,18 +561,49 @@ impl Resolc {
 
             #[cfg(feature = "svm-solc")]
             SolcCompiler::AutoDetect => {
-                if self.supported_solc_versions.matches(&_input.solc_version) {
-                    Solc::find_or_install(&_input.solc_version)
-                } else {
-                    Err(SolcError::Message(format!(
-                        "autodetected `solc` version v{} is not supported by `resolc` v{}. Set explicit `solc` version",
-                        &_input.solc_version, self.resolc_version
-                    )))
-                }
+                self.check_autodetected_solc(&_input.solc_version)?;
+                Solc::find_or_install(&_input.solc_version)
             }
         }
     }
 
+    #[cfg(feature = "svm-solc")]
+    fn check_autodetected_solc(&self, solc_version: &Version) -> Result<()> {
+        if self.supported_solc_versions.matches(solc_version) {
+            Ok(())
+        } else {
+            Err(SolcError::Message(format!(
+                "autodetected `solc` version v{} is not supported by `resolc` v{}. Set explicit `solc` version",
+                solc_version, self.resolc_version
+            )))
+        }
+    }
+
+    /// Converts the output of `resolc` and applies the settings of the input.
+    fn finish_output(
+        &self,
+        input: &ResolcVersionedInput,
+        results: ResolcCompilerOutput,
+    ) -> CompilerOutput<Error, Contract> {
+        if !input.unsupported_outputs.is_empty() {
+            let outputs = input.unsupported_outputs.iter().cloned().collect::<Vec<_>>();
+            report::unsupported_outputs("Resolc", &outputs);
+        }
+        let mut output: CompilerOutput<_, _> = results.into();
+        output.toolchain.resolc_version = Some(self.resolc_version.clone());
+        let memory_config =
+            input.input.settings.polkavm.as_ref().map(|pvm| pvm.memory_config.clone());
+        input.limits.check_output(&mut output, &memory_config.unwrap_or_default());
+        if input.input.settings.polkavm.as_ref().is_some_and(|pvm| pvm.debug_information) {
+            add_source_maps(
+                &mut output,
+                &input.input.sources,
+                input.cli_settings.base_path.as_deref(),
+            );
+        }
+        output
+    }
+
     #[instrument(level = "debug", skip_all)]
     pub fn get_version_for_path(path: &Path) -> Result<Version> {
         let mut cmd = Command::new(path);
@@ -268,6 +622,102 @@ impl Resolc {
         solc: &Solc,
         input: &ResolcInput,
     ) -> Result<Vec<u8>> {
+        let child = self.spawn_standard_json(solc, input)?;
+
+        let output = child.wait_with_output().map_err(map_io_err(&self.resolc))?;
+        debug!("Finished compiling with standard json with status {:?}", output.status);
+
+        compile_output(output)
+    }
+
+    /// Compiles with `--standard-json` and deserializes the output while it is being read from
+    /// `stdout`, instead of buffering the entire output first.
+    ///
+    /// PolkaVM blobs and assembly make the output of large projects huge, so this keeps the peak
+    /// memory usage down. `stderr` is captured on a separate thread so `resolc` can't block on a
+    /// full pipe.
+    #[instrument(name = "compile", level = "debug", skip_all)]
+    pub fn compile_standard_json(
+        &self,
+        solc: &Solc,
+        input: &ResolcInput,
+    ) -> Result<ResolcCompilerOutput> {
+        let mut child = self.spawn_standard_json(solc, input)?;
+        let (Some(stdout), Some(mut stderr)) = (child.stdout.take(), child.stderr.take()) else {
+            let err = SolcError::msg("`resolc` `stdout` closed");
+            return Err(dump_output_to_err(child, err));
+        };
+        let stderr = std::thread::spawn(move || {
+            let mut buf = Vec::new();
+            stderr.read_to_end(&mut buf).map(|_| buf)
+        });
+
+        let mut reader = io::BufReader::new(RecordingReader::new(stdout, MAX_RECORDED_STDOUT));
+        let output = serde_json::from_reader::<_, ResolcCompilerOutput>(&mut reader);
+        // Consume any remaining output so `resolc` can exit.
+        let drained = io::copy(&mut reader, &mut io::sink());
+
+        let status = child.wait().map_err(map_io_err(&self.resolc))?;
+        let stderr = stderr
+            .join()
+            .map_err(|_| SolcError::msg("failed to read `resolc` `stderr`"))?
+            .map_err(map_io_err(&self.resolc))?;
+        debug!("Finished compiling with standard json with status {status:?}");
+
+        if !status.success() {
+            let stdout = reader.into_inner().recorded;
+            return Err(SolcError::solc_output(&Output { status, stdout, stderr }));
+        }
+        drained.map_err(map_io_err(&self.resolc))?;
+        let mut output = output.map_err(|e| SolcError::msg(e.to_string()))?;
+        output.errors.extend(stderr_warnings(&stderr, None));
+        Ok(output)
+    }
+
+    /// Spawns `resolc` with `--standard-json` and writes the input to its `stdin`.
+    fn spawn_standard_json(&self, solc: &Solc, input: &ResolcInput) -> Result<Child> {
+        trace!(input=%serde_json::to_string(input).unwrap_or_else(|e| e.to_string()));
+        let mut cmd = self.standard_json_cmd(solc);
+        let mut child = cmd.spawn().map_err(map_io_err(&self.resolc))?;
+        let Some(stdin) = child.stdin.take() else {
+            let err = SolcError::msg("`resolc` `stdin` closed");
+            return Err(dump_output_to_err(child, err));
+        };
+
+        let mut writer = io::BufWriter::new(stdin);
+
+        if let Err(err) = serde_json::to_writer(&mut writer, &input) {
+            return Err(dump_output_to_err(child, err.into()));
+        }
+
+        if let Err(err) = writer.flush() {
+            return Err(dump_output_to_err(child, map_io_err(&self.resolc)(err)));
+        }
+        drop(writer);
+
+        debug!("Spawned");
+
+        Ok(child)
+    }
+
+    /// Compiles all Yul sources of the input.
+    ///
+    /// `resolc` only accepts a single file in `--yul` mode, so every source is compiled by a
+    /// separate invocation. Failures are reported as errors of the corresponding file.
+    #[instrument(name = "compile_yul", level = "debug", skip_all)]
+    pub fn compile_yul(&self, solc: &Solc, input: &ResolcInput) -> Result<ResolcCompilerOutput> {
+        let mut output = ResolcCompilerOutput::default();
+
+        for (id, (path, source)) in input.sources.iter().enumerate() {
+            let cmd_output =
+                self.yul_cmd(solc, input, path).output().map_err(map_io_err(&self.resolc))?;
+            add_yul_output(&mut output, id, path, source, &cmd_output)?;
+        }
+
+        Ok(output)
+    }
+
+    fn standard_json_cmd(&self, solc: &Solc) -> Command {
         let mut cmd = self.configure_cmd(solc);
         if !solc.allow_paths.is_empty() {
             cmd.arg("--allow-paths");
@@ -282,57 +732,219 @@ impl Resolc {
             cmd.current_dir(base_path);
         }
 
-        trace!(input=%serde_json::to_string(input).unwrap_or_else(|e| e.to_string()));
+        cmd.arg("--solc");
+        cmd.arg(&solc.solc);
+        cmd.arg("--standard-json");
         debug!(?cmd, "compiling");
+        cmd
+    }
 
-        let child = if matches!(&input.language, SolcLanguage::Solidity) {
-            cmd.arg("--solc");
-            cmd.arg(&solc.solc);
-            cmd.arg("--standard-json");
-            let mut child = cmd.spawn().map_err(map_io_err(&self.resolc))?;
-            let Some(stdin) = child.stdin.take() else {
-                let err = SolcError::msg("`resolc` `stdin` closed");
-                return Err(dump_output_to_err(child, err));
-            };
-
-            let mut writer = io::BufWriter::new(stdin);
-
-            if let Err(err) = serde_json::to_writer(&mut writer, &input) {
-                return Err(dump_output_to_err(child, err.into()));
+    fn yul_cmd(&self, solc: &Solc, input: &ResolcInput, path: &Path) -> Command {
+        let mut cmd = self.configure_cmd(solc);
+        if let Some(base_path) = &solc.base_path {
+            cmd.current_dir(base_path);
+        }
+        cmd.arg("--solc").arg(&solc.solc);
+        if let Some(mode) = input.settings.optimizer.mode {
+            cmd.arg(format!("-O{mode}"));
+        }
+        // same PolkaVM settings as for standard json inputs
+        if let Some(polkavm) = &input.settings.polkavm {
+            cmd.arg("--heap-size").arg(polkavm.memory_config.heap_size.to_string());
+            cmd.arg("--stack-size").arg(polkavm.memory_config.stack_size.to_string());
+            if polkavm.debug_information {
+                cmd.arg("-g");
             }
+        }
+        cmd.arg("--yul").arg(path).arg("--bin");
+        debug!(?cmd, "compiling");
+        cmd
+    }
+
+    fn configure_cmd(&self, solc: &Solc) -> Command {
+        let mut cmd = Command::new(&self.resolc);
+        cmd.stdin(Stdio::piped()).stderr(Stdio::piped()).stdout(Stdio::piped());
+        cmd.args(&solc.extra_args);
+        cmd
+    }
+}
 
-            if let Err(err) = writer.flush() {
-                return Err(dump_output_to_err(child, map_io_err(&self.resolc)(err)));
+#[cfg(feature = "async")]
+impl Resolc {
+    /// Compiles the input like [`Compiler::compile`], without blocking the runtime.
+    ///
+    /// If the `solc` compiler is autodetected and not installed yet, it's installed via `svm`.
+    pub async fn async_compile(
+        &self,
+        input: &ResolcVersionedInput,
+    ) -> Result<CompilerOutput<Error, Contract>> {
+        let resolc = match self.find_for_input(input)? {
+            Some(resolc) => resolc,
+            None => {
+                let req = input.resolc_version_req.clone().unwrap_or(VersionReq::STAR);
+                let (solc_version, solc_compiler) = (input.solc_version.clone(), self.solc.clone());
+                let resolc = tokio::task::spawn_blocking(move || {
+                    Self::blocking_install_matching(&req, &solc_version, solc_compiler)
+                })
+                .await
+                .map_err(|err| SolcError::msg(format!("failed to install `resolc`: {err}")))??;
+                let req = input.resolc_version_req.as_ref();
+                Cow::Owned(self.cache_resolved(req, &input.solc_version, resolc))
             }
-            child
-        } else {
-            cmd.arg("--yul");
-            cmd.arg(format!(
-                "{}",
-                &input
-                    .sources
-                    .first_key_value()
-                    .map(|k| k.0.to_string_lossy())
-                    .ok_or_else(|| SolcError::msg("No Yul sources available"))?
-            ));
-            cmd.arg("--bin");
-
-            cmd.spawn().map_err(map_io_err(&self.resolc))?
         };
+        let solc = configure_solc(resolc.async_solc(input).await?, input);
+        let results = match input.input.language {
+            SolcLanguage::Yul => resolc.async_compile_yul(&solc, &input.input).await?,
+            _ => resolc.async_compile_standard_json(&solc, &input.input).await?,
+        };
+        Ok(resolc.finish_output(input, results))
+    }
 
-        debug!("Spawned");
+    /// Async version of [`Self::compile_standard_json`].
+    ///
+    /// The output is streamed on a blocking thread like [`Self::compile_standard_json`] does.
+    #[instrument(name = "compile", level = "debug", skip_all)]
+    pub async fn async_compile_standard_json(
+        &self,
+        solc: &Solc,
+        input: &ResolcInput,
+    ) -> Result<ResolcCompilerOutput> {
+        let (resolc, solc, input) = (self.clone(), solc.clone(), input.clone());
+        tokio::task::spawn_blocking(move || resolc.compile_standard_json(&solc, &input))
+            .await
+            .map_err(|err| SolcError::msg(format!("failed to compile with `resolc`: {err}")))?
+    }
 
-        let output = child.wait_with_output().map_err(map_io_err(&self.resolc))?;
+    /// Async version of [`Self::compile_output`].
+    #[instrument(name = "compile", level = "debug", skip_all)]
+    pub async fn async_compile_output(&self, solc: &Solc, input: &ResolcInput) -> Result<Vec<u8>> {
+        compile_output(self.async_standard_json_output(solc, input).await?)
+    }
+
+    /// Async version of [`Self::compile_yul`].
+    #[instrument(name = "compile_yul", level = "debug", skip_all)]
+    pub async fn async_compile_yul(
+        &self,
+        solc: &Solc,
+        input: &ResolcInput,
+    ) -> Result<ResolcCompilerOutput> {
+        let mut output = ResolcCompilerOutput::default();
+
+        for (id, (path, source)) in input.sources.iter().enumerate() {
+            let mut cmd: tokio::process::Command = self.yul_cmd(solc, input, path).into();
+            let cmd_output = cmd.output().await.map_err(map_io_err(&self.resolc))?;
+            add_yul_output(&mut output, id, path, source, &cmd_output)?;
+        }
+
+        Ok(output)
+    }
+
+    /// Compiles all inputs with their associated `Resolc`.
+    ///
+    /// This will buffer up to `n` `resolc` processes and then return the outputs in the order in
+    /// which they complete.
+    pub async fn compile_many<I>(
+        jobs: I,
+        n: usize,
+    ) -> Vec<(Result<CompilerOutput<Error, Contract>>, Self, ResolcVersionedInput)>
+    where
+        I: IntoIterator<Item = (Self, ResolcVersionedInput)>,
+    {
+        use futures_util::stream::StreamExt;
+
+        futures_util::stream::iter(
+            jobs.into_iter().map(|(resolc, input)| async {
+                (resolc.async_compile(&input).await, resolc, input)
+            }),
+        )
+        .buffer_unordered(n)
+        .collect()
+        .await
+    }
+
+    /// Runs `resolc` with `--standard-json`, writing the input to `stdin` while the output is
+    /// being read, so neither side can block on a full pipe.
+    async fn async_standard_json_output(&self, solc: &Solc, input: &ResolcInput) -> Result<Output> {
+        use tokio::io::AsyncWriteExt;
+
+        trace!(input=%serde_json::to_string(input).unwrap_or_else(|e| e.to_string()));
+        let mut cmd: tokio::process::Command = self.standard_json_cmd(solc).into();
+        let mut child = cmd.spawn().map_err(map_io_err(&self.resolc))?;
+        let mut stdin =
+            child.stdin.take().ok_or_else(|| SolcError::msg("`resolc` `stdin` closed"))?;
+        let content = serde_json::to_vec(input)?;
+
+        let write = async move {
+            stdin.write_all(&content).await?;
+            // closes `stdin`
+            stdin.shutdown().await
+        };
+        let (written, output) = futures_util::future::join(write, child.wait_with_output()).await;
+        let output = output.map_err(map_io_err(&self.resolc))?;
         debug!("Finished compiling with standard json with status {:?}", output.status);
 
-        compile_output(output)
+        // a failed write is only relevant if `resolc` didn't report an error itself
+        if output.status.success() {
+            written.map_err(map_io_err(&self.resolc))?;
+        }
+        Ok(output)
     }
 
-    fn configure_cmd(&self, solc: &Solc) -> Command {
-        let mut cmd = Command::new(&self.resolc);
-        cmd.stdin(Stdio::piped()).stderr(Stdio::piped()).stdout(Stdio::piped());
-        cmd.args(&solc.extra_args);
-        cmd
+    /// Async version of [`Self::solc`], installing an autodetected `solc` via `svm` if needed.
+    async fn async_solc(&self, _input: &ResolcVersionedInput) -> Result<Solc> {
+        match &self.solc {
+            SolcCompiler::Specific(solc) => Ok(solc.clone()),
+
+            #[cfg(feature = "svm-solc")]
+            SolcCompiler::AutoDetect => {
+                self.check_autodetected_solc(&_input.solc_version)?;
+                match Solc::find_svm_installed_version(&_input.solc_version)? {
+                    Some(solc) => Ok(solc),
+                    None => Ok(Solc::install(&_input.solc_version).await?),
+                }
+            }
+        }
+    }
+}
+
+/// Applies the CLI settings of the input to the `solc` compiler.
+fn configure_solc(mut solc: Solc, input: &ResolcVersionedInput) -> Solc {
+    solc.base_path.clone_from(&input.cli_settings.base_path);
+    solc.allow_paths.clone_from(&input.cli_settings.allow_paths);
+    solc.include_paths.clone_from(&input.cli_settings.include_paths);
+    solc.extra_args.extend_from_slice(&input.cli_settings.extra_args);
+    solc
+}
+
+fn no_matching_resolc(req: &VersionReq, solc_version: &Version) -> SolcError {
+    SolcError::msg(format!(
+        "no `resolc` version matching \"{req}\" supports `solc` v{solc_version}"
+    ))
+}
+
+/// The maximum number of bytes of the streamed `stdout` kept for error reporting.
+const MAX_RECORDED_STDOUT: usize = 64 * 1024;
+
+/// Keeps the first bytes read from the inner reader, so the output of a failed compilation can be
+/// reported without buffering the entire output of a successful one.
+struct RecordingReader<R> {
+    inner: R,
+    recorded: Vec<u8>,
+    limit: usize,
+}
+
+impl<R> RecordingReader<R> {
+    fn new(inner: R, limit: usize) -> Self {
+        Self { inner, recorded: Vec::new(), limit }
+    }
+}
+
+impl<R: Read> Read for RecordingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        let keep = n.min(self.limit - self.recorded.len());
+        self.recorded.extend_from_slice(&buf[..keep]);
+        Ok(n)
     }
 }
 
@@ -353,8 +965,7 @@ fn version_from_output(output: Output) -> Result<Version> {
         let stdout = String::from_utf8_lossy(&output.stdout);
         let version = stdout
             .lines()
-            .filter(|l| !l.trim().is_empty())
-            .next_back()
+            .rfind(|l| !l.trim().is_empty())
             .ok_or_else(|| SolcError::msg("Version not found in resolc output"))?;
         let version = version.split_terminator("version ");
         version
@@ -377,21 +988,690 @@ fn version_from_output(output: Output) -> Result<Version> {
 }
 
 fn compile_output(output: Output) -> Result<Vec<u8>> {
-    // @TODO: Handle YUL output
     if output.status.success() {
+        for warning in stderr_warnings(&output.stderr, None) {
+            warn!("{warning}");
+        }
         Ok(output.stdout)
     } else {
         Err(SolcError::solc_output(&output))
     }
 }
 
+/// Parses the `--bin` output of `resolc` into `(identifier, bytecode)` pairs.
+///
+/// Accepts both the ``Contract `<identifier>` bytecode: 0x<hex>`` lines and `solc` style
+/// `======= <identifier> =======` headers followed by a `Binary:` section.
+fn parse_yul_output(stdout: &str) -> Result<Vec<(String, Bytes)>> {
+    let decode = |code: &str| {
+        hex::decode(code.trim())
+            .map(Bytes::from)
+            .map_err(|err| SolcError::msg(format!("invalid `resolc` bytecode output: {err}")))
+    };
+
+    let mut contracts = Vec::new();
+    let mut identifier = None;
+    let mut lines = stdout.lines().map(str::trim);
+    while let Some(line) = lines.next() {
+        if let Some((id, code)) =
+            line.strip_prefix("Contract `").and_then(|rest| rest.split_once("` bytecode:"))
+        {
+            contracts.push((id.to_string(), decode(code)?));
+        } else if let Some(id) =
+            line.strip_prefix("=======").and_then(|rest| rest.strip_suffix("======="))
+        {
+            identifier = Some(id.trim().to_string());
+        } else if line == "Binary:" {
+            if let (Some(id), Some(code)) = (identifier.take(), lines.next()) {
+                contracts.push((id, decode(code)?));
+            }
+        }
+    }
+
+    Ok(contracts)
+}
+
+/// Returns the name of the top-level object of the Yul source, if it starts with an
+/// `object "<name>"` declaration.
+fn yul_object_name(content: &str) -> Option<&str> {
+    let rest = skip_yul_trivia(content).strip_prefix("object")?;
+    if !rest.starts_with(|c: char| c.is_whitespace() || c == '"' || c == '/') {
+        return None;
+    }
+    let rest = skip_yul_trivia(rest).strip_prefix('"')?;
+    rest.split_once('"').map(|(name, _)| name)
+}
+
+/// Skips leading whitespace and comments.
+fn skip_yul_trivia(mut content: &str) -> &str {
+    loop {
+        content = content.trim_start();
+        if let Some(rest) = content.strip_prefix("//") {
+            content = rest.split_once('\n').map_or("", |(_, rest)| rest);
+        } else if let Some(rest) = content.strip_prefix("/*") {
+            content = rest.split_once("*/").map_or("", |(_, rest)| rest);
+        } else {
+            return content;
+        }
+    }
+}
+
+/// Adds the result of compiling the Yul source with `--yul` to the output.
+fn add_yul_output(
+    output: &mut ResolcCompilerOutput,
+    id: usize,
+    path: &Path,
+    source: &Source,
+    cmd_output: &Output,
+) -> Result<()> {
+    debug!("Finished compiling {} with status {:?}", path.display(), cmd_output.status);
+
+    output.sources.insert(path.to_path_buf(), SourceFile { id: id as u32, ast: None });
+
+    if !cmd_output.status.success() {
+        output.errors.push(yul_error(path, cmd_output));
+        return Ok(());
+    }
+    output.errors.extend(stderr_warnings(&cmd_output.stderr, Some(path)));
+
+    let stdout = String::from_utf8_lossy(&cmd_output.stdout);
+    let contracts = output.contracts.entry(path.to_path_buf()).or_default();
+    for (identifier, bytecode) in parse_yul_output(&stdout)? {
+        let name = match identifier.rsplit_once(':') {
+            Some((_, name)) => name.to_string(),
+            None => yul_object_name(&source.content)
+                .or_else(|| path.file_stem().and_then(|stem| stem.to_str()))
+                .unwrap_or(&identifier)
+                .to_string(),
+        };
+        let evm = ResolcEVM {
+            bytecode: Some(BytecodeObject::Bytecode(bytecode).into()),
+            ..Default::default()
+        };
+        contracts.insert(name, ResolcContract { evm: Some(evm), ..Default::default() });
+    }
+    Ok(())
+}
+
+/// Converts a failed `--yul` invocation into an error of the compiled file.
+fn yul_error(path: &Path, output: &Output) -> Error {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let message = if stderr.trim().is_empty() {
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    } else {
+        stderr.trim().to_string()
+    };
+
+    Error {
+        source_location: Some(SourceLocation {
+            file: path.display().to_string(),
+            start: -1,
+            end: -1,
+        }),
+        secondary_source_locations: Vec::new(),
+        r#type: "YulException".to_string(),
+        component: "general".to_string(),
+        severity: Severity::Error,
+        error_code: None,
+        formatted_message: Some(format!("YulException: {message}")),
+        message,
+    }
+}
+
+/// Adds the source maps derived from the debug information of the PolkaVM blobs to the contracts.
+///
+/// The paths of the debug information are matched against the compiled sources, see
+/// [`debug_source_path`].
+fn add_source_maps(
+    output: &mut CompilerOutput<Error, Contract>,
+    sources: &Sources,
+    base_path: Option<&Path>,
+) {
+    let CompilerOutput { contracts, sources: source_files, .. } = output;
+    let source = |debug_path: &str| {
+        let path = debug_source_path(sources, base_path, Path::new(debug_path))?;
+        Some((source_files.get(path)?.id, sources[path].content.as_str()))
+    };
+
+    for (file, contracts) in contracts.iter_mut() {
+        for (name, contract) in contracts.iter_mut() {
+            let Some(blob) = contract.bin_runtime_ref().and_then(|code| code.as_bytes()) else {
+                continue;
+            };
+            match polkavm_source_map(blob, source) {
+                Ok(source_map) => contract.polkavm_source_map = source_map,
+                Err(err) => {
+                    warn!(?file, name, %err, "failed to read the PolkaVM debug information")
+                }
+            }
+        }
+    }
+}
+
+/// Returns the compiled source a path of the PolkaVM debug information refers to.
+///
+/// The sources are relative to the base path, while the debug information may contain absolute
+/// paths. Paths which don't match a source exactly are matched by their trailing components, as
+/// long as only a single source matches.
+fn debug_source_path<'a>(
+    sources: &'a Sources,
+    base_path: Option<&Path>,
+    debug_path: &Path,
+) -> Option<&'a Path> {
+    let path = base_path.and_then(|base| debug_path.strip_prefix(base).ok()).unwrap_or(debug_path);
+    let path = path.strip_prefix(".").unwrap_or(path);
+    if let Some((path, _)) = sources.get_key_value(path) {
+        return Some(path);
+    }
+
+    let mut matches = sources.keys().filter(|source| path.ends_with(source));
+    let source = matches.next()?;
+    matches.next().is_none().then_some(source.as_path())
+}
+
+/// Converts the `stderr` output of a successful `resolc` invocation into warnings.
+///
+/// Every paragraph of the output becomes a separate warning with the
+/// [`STDERR_WARNING_ERROR_CODE`], so the warnings can be ignored like other diagnostics. The
+/// location is taken from a `--> file:line:column` line if present, falling back to `file`.
+fn stderr_warnings(stderr: &[u8], file: Option<&Path>) -> Vec<Error> {
+    let stderr = String::from_utf8_lossy(stderr);
+    let mut warnings = Vec::new();
+    let mut paragraph = Vec::new();
+    for line in stderr.lines().map(str::trim_end).chain([""]) {
+        if !line.trim().is_empty() {
+            paragraph.push(line);
+            continue;
+        }
+        if paragraph.is_empty() {
+            continue;
+        }
+
+        let location = paragraph
+            .iter()
+            .find_map(|line| line.trim_start().strip_prefix("-->"))
+            .map(location_file)
+            .map(str::to_string)
+            .or_else(|| file.map(|file| file.display().to_string()));
+        let text = paragraph.join("\n");
+        let message = text.strip_prefix("Warning:").unwrap_or(&text).trim().to_string();
+        paragraph.clear();
+
+        warnings.push(Error {
+            source_location: location.map(|file| SourceLocation { file, start: -1, end: -1 }),
+            secondary_source_locations: Vec::new(),
+            r#type: "Warning".to_string(),
+            component: "general".to_string(),
+            severity: Severity::Warning,
+            error_code: Some(STDERR_WARNING_ERROR_CODE),
+            formatted_message: Some(format!("Warning: {message}")),
+            message,
+        });
+    }
+    warnings
+}
+
+/// Returns the file of a `file:line:column` location, which may contain colons itself, like
+/// Windows paths do.
+fn location_file(location: &str) -> &str {
+    let location = location.trim().trim_end_matches(':');
+    let mut parts = location.rsplitn(3, ':');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(column), Some(line), Some(file))
+            if column.parse::<u32>().is_ok() && line.parse::<u32>().is_ok() =>
+        {
+            file
+        }
+        _ => location,
+    }
+}
+
 #[cfg(test)]
-#[cfg(feature = "full")]
 mod test {
-    use super::Resolc;
-    use crate::solc::Solc;
+    use super::*;
+    use crate::compilers::resolc::ResolcSettings;
+
+    #[test]
+    fn can_parse_yul_output() {
+        let output = parse_yul_output("Contract `SimpleStore.yul` bytecode: 0x50564d00\n").unwrap();
+        assert_eq!(output.len(), 1);
+        assert_eq!(output[0].0, "SimpleStore.yul");
+        assert_eq!(output[0].1.as_ref(), [0x50, 0x56, 0x4d, 0x00]);
+
+        let output =
+            parse_yul_output("\n======= src/A.yul:A =======\nBinary:\n50564d00\n").unwrap();
+        assert_eq!(output.len(), 1);
+        assert_eq!(output[0].0, "src/A.yul:A");
+        assert_eq!(output[0].1.as_ref(), [0x50, 0x56, 0x4d, 0x00]);
+
+        assert!(parse_yul_output("Contract `A.yul` bytecode: 0xzz").is_err());
+    }
+
+    #[test]
+    fn can_find_yul_object_name() {
+        assert_eq!(yul_object_name("object \"SimpleStore\" {\n  code { }\n}"), Some("SimpleStore"));
+        assert_eq!(yul_object_name("{ sstore(0, 1) }"), None);
+        assert_eq!(
+            yul_object_name(
+                "// the object \"Wrong\"\n/* object \"Wrong\" */\nobject/**/\"A\" { code { } }"
+            ),
+            Some("A")
+        );
+        assert_eq!(yul_object_name("{ let objects := 1 }\nobject \"A\" {}"), None);
+        assert_eq!(yul_object_name("objects \"A\" {}"), None);
+    }
+
+    #[test]
+    fn forwards_polkavm_settings_to_yul_cmd() {
+        let solc = Solc::new_with_version("solc", Version::new(0, 8, 28));
+        let resolc = Resolc {
+            resolc: "resolc".into(),
+            resolc_version: Version::parse("0.1.0-dev.13").unwrap(),
+            supported_solc_versions: VersionReq::STAR,
+            solc: SolcCompiler::Specific(solc.clone()),
+            locator: ResolcLocator::empty(),
+        };
+        let args = |input: &ResolcInput| {
+            resolc
+                .yul_cmd(&solc, input, Path::new("A.yul"))
+                .get_args()
+                .map(|arg| arg.to_string_lossy().into_owned())
+                .collect::<Vec<_>>()
+        };
+
+        let mut input = ResolcInput::default();
+        assert!(!args(&input).iter().any(|arg| arg == "--heap-size" || arg == "-g"));
+
+        let settings = ResolcSettings::new(Some('z'), Some(1024), Some(2048), Some(true)).unwrap();
+        input.settings.optimizer.mode = settings.resolc_optimizer.mode;
+        input.settings.polkavm = settings.polkavm;
+        let args = args(&input);
+        assert!(args.windows(2).any(|args| args == ["--heap-size", "1024"]), "{args:?}");
+        assert!(args.windows(2).any(|args| args == ["--stack-size", "2048"]), "{args:?}");
+        assert!(args.iter().any(|arg| arg == "-g") && args.iter().any(|arg| arg == "-Oz"));
+    }
+
+    #[cfg(unix)]
+    fn stub_resolc(dir: &Path, script: &str) -> (Resolc, Solc) {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = dir.join("resolc");
+        std::fs::write(&path, format!("#!/bin/sh\ncat > /dev/null\n{script}\n")).unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let solc = Solc::new_with_version("solc", Version::new(0, 8, 28));
+        let resolc = Resolc {
+            resolc: path,
+            resolc_version: Version::parse("0.1.0-dev.13").unwrap(),
+            supported_solc_versions: VersionReq::STAR,
+            solc: SolcCompiler::Specific(solc.clone()),
+            locator: ResolcLocator::empty(),
+        };
+        (resolc, solc)
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn can_stream_standard_json_output() {
+        let tmp = tempfile::tempdir().unwrap();
+        // a lot of `stderr` output must not block `resolc` while `stdout` is being read
+        let (resolc, solc) = stub_resolc(
+            tmp.path(),
+            r#"head -c 1000000 /dev/zero >&2
+echo '{"contracts":{"A.sol":{"A":{"hash":"01"}}},"version":"0.8.28"}'"#,
+        );
+
+        let output = resolc.compile_standard_json(&solc, &ResolcInput::default()).unwrap();
+        assert_eq!(output.version.as_deref(), Some("0.8.28"));
+        assert_eq!(output.contracts[Path::new("A.sol")]["A"].hash.as_deref(), Some("01"));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn can_limit_resolc_jobs_of_project() {
+        use crate::{
+            solc::{Restriction, SolcRestrictions},
+            ConfigurableArtifacts, ProjectBuilder, ProjectPathsConfig, RestrictionsWithVersion,
+        };
+        use std::{collections::BTreeMap, fs};
+
+        let tmp = tempfile::tempdir().unwrap();
+        let running = tmp.path().join("running");
+        let counts = tmp.path().join("counts");
+        fs::create_dir(&running).unwrap();
+        // records the number of `resolc` processes running at once
+        let (resolc, _) = stub_resolc(
+            tmp.path(),
+            &format!(
+                r#"touch "{running}/$$"
+ls "{running}" | wc -l >> "{counts}"
+sleep 0.3
+rm "{running}/$$"
+echo '{{}}'"#,
+                running = running.display(),
+                counts = counts.display()
+            ),
+        );
+
+        let paths = ProjectPathsConfig::dapptools(tmp.path()).unwrap();
+        fs::create_dir_all(&paths.sources).unwrap();
+        // every source requires its own settings profile, so each is compiled by a separate job
+        let mut additional_settings = BTreeMap::new();
+        let mut restrictions = BTreeMap::new();
+        for runs in 1..=4 {
+            let path = paths.sources.join(format!("C{runs}.sol"));
+            fs::write(&path, format!("pragma solidity ^0.8.0;\ncontract C{runs} {{}}\n")).unwrap();
+            let mut settings = SolcSettings::default();
+            settings.settings.optimizer.enabled = Some(true);
+            settings.settings.optimizer.runs = Some(runs);
+            additional_settings.insert(format!("runs-{runs}"), settings);
+            let restriction = SolcRestrictions {
+                optimizer_runs: Restriction { min: Some(runs), max: Some(runs) },
+                ..Default::default()
+            };
+            restrictions
+                .insert(path, RestrictionsWithVersion { version: None, restrictions: restriction });
+        }
+
+        let max_parallel_jobs = |jobs: Option<usize>| {
+            let mut builder =
+                ProjectBuilder::<Resolc, ConfigurableArtifacts>::new(Default::default())
+                    .paths(paths.clone())
+                    .additional_settings(additional_settings.clone())
+                    .restrictions(restrictions.clone())
+                    .solc_jobs(4)
+                    .ephemeral()
+                    .no_artifacts();
+            if let Some(jobs) = jobs {
+                builder = builder.compiler_jobs(Resolc::JOB_KEY, jobs);
+            }
+            let compiled = builder.build(resolc.clone()).unwrap().compile().unwrap();
+            compiled.assert_success();
+            let recorded = fs::read_to_string(&counts).unwrap();
+            fs::remove_file(&counts).unwrap();
+            recorded.lines().map(|count| count.trim().parse::<usize>().unwrap()).max().unwrap()
+        };
+
+        assert_eq!(max_parallel_jobs(Some(1)), 1);
+        assert!(max_parallel_jobs(None) > 1);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn uses_configured_resolc_if_it_satisfies_the_input() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (resolc, solc) = stub_resolc(tmp.path(), "exit 1");
+        let mut input = <ResolcVersionedInput as crate::CompilerInput>::build(
+            Sources::new(),
+            SolcSettings::default(),
+            SolcLanguage::Solidity,
+            solc.version,
+        );
+        assert!(matches!(resolc.for_input(&input).unwrap(), Cow::Borrowed(_)));
+
+        input.resolc_version_req = Some(">=0.1.0-dev.13, <0.2.0".parse().unwrap());
+        assert!(matches!(resolc.for_input(&input).unwrap(), Cow::Borrowed(_)));
+        assert_eq!(Compiler::compiler_version(&resolc, &input), resolc.resolc_version);
+    }
 
     #[test]
+    #[cfg(unix)]
+    fn resolves_resolc_once_per_version_group() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let tmp = tempfile::tempdir().unwrap();
+        let (resolc, solc) = stub_resolc(tmp.path(), "exit 1");
+        let bin = tmp.path().join("bin");
+        std::fs::create_dir(&bin).unwrap();
+        let dev16 = bin.join("resolc-0.1.0-dev.16");
+        std::fs::write(
+            &dev16,
+            "#!/bin/sh\n\
+             case \"$1\" in\n\
+             --version) echo \"Solidity frontend for the revive compiler version \
+             0.1.0-dev.16+commit.d4e5f6a\" ;;\n\
+             --supported-solc-versions) echo \">=0.8.0, <=0.8.29\" ;;\n\
+             *) exit 1 ;;\n\
+             esac\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&dev16, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let resolc = resolc.with_locator(ResolcLocator::empty().dir(&bin));
+
+        let mut input = <ResolcVersionedInput as crate::CompilerInput>::build(
+            Sources::new(),
+            SolcSettings::default(),
+            SolcLanguage::Solidity,
+            solc.version,
+        );
+        input.resolc_version_req = Some("=0.1.0-dev.16".parse().unwrap());
+        let version = Version::parse("0.1.0-dev.16").unwrap();
+        assert_eq!(
+            resolc
+                .resolve(input.resolc_version_req.as_ref(), &input.solc_version, true)
+                .unwrap()
+                .resolc,
+            dev16
+        );
+        assert_eq!(Compiler::compiler_version(&resolc, &input), version);
+
+        // the resolved binary is only reused while it exists
+        std::fs::remove_dir_all(&bin).unwrap();
+        assert!(resolc.find_for_input(&input).unwrap().is_none());
+        assert_eq!(Compiler::compiler_version(&resolc, &input), resolc.resolc_version);
+        let err = resolc
+            .resolve(input.resolc_version_req.as_ref(), &input.solc_version, true)
+            .unwrap_err();
+        assert!(err.to_string().contains("offline mode"), "{err}");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn compiles_sources_resolving_to_the_same_resolc_together() {
+        use crate::{ConfigurableArtifacts, ProjectBuilder, ProjectPathsConfig};
+        use std::fs;
+
+        let tmp = tempfile::tempdir().unwrap();
+        let jobs = tmp.path().join("jobs");
+        // records every `resolc` invocation
+        let (mut resolc, _) =
+            stub_resolc(tmp.path(), &format!("echo job >> \"{}\"\necho '{{}}'", jobs.display()));
+        resolc.resolc_version = Version::new(0, 3, 0);
+
+        let paths = ProjectPathsConfig::dapptools(tmp.path()).unwrap();
+        fs::create_dir_all(&paths.sources).unwrap();
+        let source = |name: &str, content: &str| {
+            fs::write(paths.sources.join(name), content).unwrap();
+        };
+        source("Shared.sol", "pragma solidity ^0.8.0;\ncontract Shared {}\n");
+        source("A.sol", "pragma solidity ^0.8.0;\nimport \"./Shared.sol\";\ncontract A {}\n");
+        source(
+            "B.sol",
+            "// pragma resolc >=0.3.0;\npragma solidity ^0.8.0;\nimport \"./Shared.sol\";\n\
+             contract B {}\n",
+        );
+
+        let project = |offline: bool| {
+            let mut builder =
+                ProjectBuilder::<Resolc, ConfigurableArtifacts>::new(Default::default())
+                    .paths(paths.clone())
+                    .ephemeral()
+                    .no_artifacts();
+            if offline {
+                builder = builder.offline();
+            }
+            builder.build(resolc.clone()).unwrap()
+        };
+
+        // the configured `resolc` satisfies all sources, so they are compiled by a single job
+        project(true).compile().unwrap().assert_success();
+        assert_eq!(fs::read_to_string(&jobs).unwrap().lines().count(), 1);
+
+        // sources requiring an unavailable `resolc` fail instead of being installed offline
+        source(
+            "C.sol",
+            "// pragma resolc >=9.0.0;\npragma solidity ^0.8.0;\nimport \"./Shared.sol\";\n\
+             contract C {}\n",
+        );
+        let err = project(true).compile().unwrap_err();
+        assert!(err.to_string().contains(">=9.0.0"), "{err}");
+    }
+
+    #[test]
+    fn can_match_debug_source_paths() {
+        let sources = Sources::from([
+            ("src/A.sol".into(), Source::new("")),
+            ("A.sol".into(), Source::new("")),
+            ("lib/B.sol".into(), Source::new("")),
+        ]);
+        let base = Path::new("/project");
+        let path =
+            |debug_path: &str| debug_source_path(&sources, Some(base), Path::new(debug_path));
+
+        assert_eq!(path("/project/A.sol"), Some(Path::new("A.sol")));
+        assert_eq!(path("./A.sol"), Some(Path::new("A.sol")));
+        assert_eq!(path("/elsewhere/lib/B.sol"), Some(Path::new("lib/B.sol")));
+        // `src/A.sol` also ends with the source `A.sol`
+        assert_eq!(path("/project/src/A.sol"), Some(Path::new("src/A.sol")));
+        // ambiguous matches are skipped
+        assert_eq!(path("/elsewhere/src/A.sol"), None);
+        assert_eq!(path("/project/lib/C.sol"), None);
+    }
+
+    #[test]
+    fn can_convert_stderr_to_warnings() {
+        let stderr = b"Warning: unused variable\n --> src/A.sol:3:5:\n  |\n\n\nnote: fallback\n";
+        let warnings = stderr_warnings(stderr, Some(Path::new("src/B.sol")));
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings
+            .iter()
+            .all(|w| w.severity == Severity::Warning
+                && w.error_code == Some(STDERR_WARNING_ERROR_CODE)));
+        assert!(warnings[0].message.starts_with("unused variable\n --> src/A.sol"));
+        assert_eq!(warnings[0].source_location.as_ref().unwrap().file, "src/A.sol");
+        assert_eq!(warnings[1].message, "note: fallback");
+        assert_eq!(warnings[1].source_location.as_ref().unwrap().file, "src/B.sol");
+
+        let warnings = stderr_warnings(b"Warning: unused\n --> C:\\src\\A.sol:3:5\n", None);
+        assert_eq!(warnings[0].source_location.as_ref().unwrap().file, "C:\\src\\A.sol");
+        let warnings = stderr_warnings(b"Warning: unused\n --> C:\\src\\A.sol\n", None);
+        assert_eq!(warnings[0].source_location.as_ref().unwrap().file, "C:\\src\\A.sol");
+
+        assert!(stderr_warnings(b" \n\n", None).is_empty());
+        assert!(stderr_warnings(b"warning", None)[0].source_location.is_none());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn reports_stderr_of_successful_compilation() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (resolc, solc) = stub_resolc(
+            tmp.path(),
+            r#"echo 'Warning: the solc version is not tested with resolc' >&2
+echo '{"contracts":{},"version":"0.8.28"}'"#,
+        );
+
+        let output = resolc.compile_standard_json(&solc, &ResolcInput::default()).unwrap();
+        assert_eq!(output.errors.len(), 1);
+        assert_eq!(output.errors[0].message, "the solc version is not tested with resolc");
+        assert_eq!(output.errors[0].error_code, Some(STDERR_WARNING_ERROR_CODE));
+        assert!(!output.errors[0].is_error());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn reports_standard_json_failure() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (resolc, solc) = stub_resolc(tmp.path(), "echo 'invalid input' >&2\nexit 1");
+        let err = resolc.compile_standard_json(&solc, &ResolcInput::default()).unwrap_err();
+        assert!(err.to_string().contains("invalid input"), "{err}");
+
+        let (resolc, solc) = stub_resolc(tmp.path(), "echo '{\"contracts\":'");
+        let err = resolc.compile_standard_json(&solc, &ResolcInput::default()).unwrap_err();
+        assert!(err.to_string().contains("EOF"), "{err}");
+
+        // errors reported via `stdout` are kept
+        let (resolc, solc) =
+            stub_resolc(tmp.path(), "echo '{\"errors\":[{\"message\":\"no solc\"}]}'\nexit 1");
+        let err = resolc.compile_standard_json(&solc, &ResolcInput::default()).unwrap_err();
+        assert!(err.to_string().contains("no solc"), "{err}");
+    }
+
+    #[test]
+    fn records_limited_output() {
+        let mut reader = RecordingReader::new(&b"0123456789"[..], 4);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).unwrap();
+        assert_eq!(buf, b"0123456789");
+        assert_eq!(reader.recorded, b"0123");
+    }
+
+    #[tokio::test]
+    #[cfg(all(unix, feature = "async"))]
+    async fn can_compile_async() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (resolc, solc) = stub_resolc(
+            tmp.path(),
+            r#"echo 'Warning: the solc version is not tested with resolc' >&2
+echo '{"contracts":{"A.sol":{"A":{"hash":"01"}}},"version":"0.8.28"}'"#,
+        );
+        let input = <ResolcVersionedInput as crate::CompilerInput>::build(
+            Sources::new(),
+            SolcSettings::default(),
+            SolcLanguage::Solidity,
+            solc.version.clone(),
+        );
+
+        let output = resolc.async_compile(&input).await.unwrap();
+        assert!(output.contracts[Path::new("A.sol")].contains_key("A"));
+        assert_eq!(output.errors.len(), 1);
+        assert_eq!(output.errors[0].error_code, Some(STDERR_WARNING_ERROR_CODE));
+        assert_eq!(output.toolchain.resolc_version, Some("0.1.0-dev.13".parse().unwrap()));
+
+        let outputs = Resolc::compile_many(vec![(resolc.clone(), input.clone()); 3], 2).await;
+        assert_eq!(outputs.len(), 3);
+        assert!(outputs.iter().all(|(output, ..)| output.is_ok()));
+
+        let (resolc, _) = stub_resolc(tmp.path(), "echo 'invalid input' >&2\nexit 1");
+        let err = resolc.async_compile(&input).await.unwrap_err();
+        assert!(err.to_string().contains("invalid input"), "{err}");
+    }
+
+    #[tokio::test]
+    #[cfg(all(unix, feature = "async"))]
+    async fn can_stream_standard_json_output_async() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (resolc, solc) = stub_resolc(
+            tmp.path(),
+            r#"head -c 1000000 /dev/zero >&2
+echo '{"contracts":{"A.sol":{"A":{"hash":"01"}}},"version":"0.8.28"}'"#,
+        );
+
+        let output =
+            resolc.async_compile_standard_json(&solc, &ResolcInput::default()).await.unwrap();
+        assert_eq!(output.contracts[Path::new("A.sol")]["A"].hash.as_deref(), Some("01"));
+
+        let (resolc, _) = stub_resolc(tmp.path(), "echo '{\"contracts\":' \nexit 1");
+        let err =
+            resolc.async_compile_standard_json(&solc, &ResolcInput::default()).await.unwrap_err();
+        assert!(err.to_string().contains("contracts"), "{err}");
+    }
+
+    #[tokio::test]
+    #[cfg(all(unix, feature = "async"))]
+    async fn can_compile_yul_async() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (resolc, solc) =
+            stub_resolc(tmp.path(), "echo 'Contract `A.yul` bytecode: 0x50564d00'");
+        let mut input = ResolcInput { language: SolcLanguage::Yul, ..Default::default() };
+        input.sources.insert("A.yul".into(), Source::new("object \"A\" { code { } }"));
+
+        let output = resolc.async_compile_yul(&solc, &input).await.unwrap();
+        assert!(output.errors.is_empty());
+        assert!(output.contracts[Path::new("A.yul")]["A"].evm.is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "full")]
     fn not_existing_version() {
         let result = Resolc::install(
             semver::Version::parse("0.1.0-dev.33").ok().as_ref(),
@@ -401,11 +1681,25 @@ mod test {
         assert_eq!(result.to_string(), "Unknown version of Resolc v0.1.0-dev.33.")
     }
 
+    #[tokio::test]
+    #[cfg(feature = "full")]
+    async fn not_existing_version_async() {
+        let result = Resolc::async_install(
+            semver::Version::parse("0.1.0-dev.33").ok().as_ref(),
+            crate::solc::SolcCompiler::AutoDetect,
+        )
+        .await
+        .expect_err("should fail");
+        assert_eq!(result.to_string(), "Unknown version of Resolc v0.1.0-dev.33.")
+    }
+
+    #[cfg(feature = "full")]
     fn solc_with_version() -> Solc {
         Solc::blocking_install(&semver::Version::parse("0.4.14").unwrap()).unwrap()
     }
 
     #[test]
+    #[cfg(feature = "full")]
     fn not_existing_solc() {
         let result = Resolc::install(
             semver::Version::parse("0.1.0-dev.13").ok().as_ref(),
@@ -3,21 +3,91 @@ use foundry_compilers_artifacts::{
     Libraries, ModelCheckerSettings, OptimizerDetails, Remapping, Settings, SettingsMetadata,
     SolcLanguage, Source, Sources,
 };
-use foundry_compilers_core::utils::strip_prefix_owned;
+use foundry_compilers_core::{
+    error::{Result, SolcError},
+    utils::{find_resolc_version_pragma, strip_prefix_owned},
+};
 use revive_solc_json_interface::SolcStandardJsonInputSettingsPolkaVMMemory;
-use semver::Version;
+use semver::{Version, VersionReq};
 use serde::{Deserialize, Serialize};
-use std::{collections::HashSet, path::Path};
+use std::{collections::BTreeSet, fmt, path::Path};
 
+use super::{PolkaVmLimits, Resolc};
 use crate::{
-    solc::{CliSettings, SolcSettings},
-    CompilerInput, CompilerSettings,
+    buildinfo::RawBuildInfo,
+    resolver::parse::SolData,
+    solc::{CliSettings, Restriction, SolcSettings, SolcVersionedInput},
+    CompilationTarget, CompilerInput, CompilerSettingsRestrictions,
 };
 
 #[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ResolcOptimizer {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub mode: Option<char>,
+    pub mode: Option<ResolcOptimizerMode>,
+}
+
+/// The `resolc` optimizer mode, passed as `-O<mode>`.
+///
+/// (De)serialized as the single character used on the command line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "char", into = "char")]
+pub enum ResolcOptimizerMode {
+    /// `0`: no optimizations.
+    None,
+    /// `1`: basic optimizations.
+    Basic,
+    /// `2`: default optimizations.
+    Default,
+    /// `3`: aggressive optimizations for performance.
+    Aggressive,
+    /// `s`: optimize for size.
+    Size,
+    /// `z`: aggressively optimize for size.
+    MinSize,
+}
+
+impl ResolcOptimizerMode {
+    /// All optimizer modes supported by `resolc`.
+    pub const ALL: [Self; 6] =
+        [Self::None, Self::Basic, Self::Default, Self::Aggressive, Self::Size, Self::MinSize];
+
+    /// The mode `resolc` optimizes with if none is set.
+    pub const RESOLC_DEFAULT: Self = Self::Aggressive;
+
+    /// Returns the character identifying the mode on the command line.
+    pub const fn as_char(&self) -> char {
+        match self {
+            Self::None => '0',
+            Self::Basic => '1',
+            Self::Default => '2',
+            Self::Aggressive => '3',
+            Self::Size => 's',
+            Self::MinSize => 'z',
+        }
+    }
+}
+
+impl TryFrom<char> for ResolcOptimizerMode {
+    type Error = SolcError;
+
+    fn try_from(mode: char) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|m| m.as_char() == mode)
+            .ok_or(SolcError::InvalidResolcOptimizerMode(mode))
+    }
+}
+
+impl From<ResolcOptimizerMode> for char {
+    fn from(mode: ResolcOptimizerMode) -> Self {
+        mode.as_char()
+    }
+}
+
+impl fmt::Display for ResolcOptimizerMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_char())
+    }
 }
 
 #[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -48,15 +118,33 @@ pub struct ResolcSettings {
     pub polkavm: Option<PolkaVM>,
     #[serde(default)]
     pub resolc_optimizer: ResolcOptimizer,
+    /// The target to compile for if both EVM and PolkaVM artifacts are built, see
+    /// [`SolidityCompiler::Dual`](crate::multi::SolidityCompiler::Dual).
+    #[serde(default, skip_serializing_if = "CompilationTarget::is_evm")]
+    pub target: CompilationTarget,
+    /// The limits compiled contracts are checked against.
+    #[serde(default, skip_serializing_if = "PolkaVmLimits::is_empty")]
+    pub limits: PolkaVmLimits,
+    /// The `resolc` version required by the project.
+    ///
+    /// Combined with the `// pragma resolc <version>;` comments of the sources, see
+    /// [`Resolc::for_input`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolc_version: Option<VersionReq>,
 }
 
 impl ResolcSettings {
+    /// Creates new settings, validating the optimizer mode.
+    ///
+    /// Returns [`SolcError::InvalidResolcOptimizerMode`] if `optimizer_mode` is not a mode
+    /// supported by `resolc`.
     pub fn new(
         optimizer_mode: Option<char>,
         heap_size: Option<u32>,
         stack_size: Option<u32>,
         debug_information: Option<bool>,
-    ) -> Self {
+    ) -> Result<Self> {
+        let mode = optimizer_mode.map(ResolcOptimizerMode::try_from).transpose()?;
         let has_custom_settings =
             debug_information.is_some() || heap_size.is_some() || stack_size.is_some();
 
@@ -81,7 +169,114 @@ impl ResolcSettings {
             None
         };
 
-        Self { resolc_optimizer: ResolcOptimizer { mode: optimizer_mode }, polkavm }
+        Ok(Self { resolc_optimizer: ResolcOptimizer { mode }, polkavm, ..Default::default() })
+    }
+
+    /// Returns true if artifacts compiled by `resolc` with the `other` settings can be reused with
+    /// these settings.
+    ///
+    /// The memory configuration, the debug information and the optimizer mode all affect the
+    /// emitted PolkaVM blob. Unset PolkaVM settings are equivalent to their defaults. Changed
+    /// limits require recompiling so the new limits are reported.
+    pub fn can_use_cached(&self, other: &Self) -> bool {
+        let default = PolkaVM::default();
+        self.polkavm.as_ref().unwrap_or(&default) == other.polkavm.as_ref().unwrap_or(&default)
+            && self.resolc_optimizer == other.resolc_optimizer
+            && self.target == other.target
+            && self.limits == other.limits
+    }
+
+    /// Returns whether the settings satisfy the given restrictions.
+    ///
+    /// Unset PolkaVM settings and an unset optimizer mode are checked against their defaults, see
+    /// [`ResolcOptimizerMode::RESOLC_DEFAULT`].
+    pub fn satisfies_restrictions(&self, restrictions: &ResolcRestrictions) -> bool {
+        let ResolcRestrictions { heap_size, stack_size, optimizer_modes } = restrictions;
+        let memory_config = self.polkavm.as_ref().map(|pvm| pvm.memory_config.clone());
+        let MemoryConfig { heap_size: heap, stack_size: stack } = memory_config.unwrap_or_default();
+
+        heap_size.satisfies(Some(heap))
+            && stack_size.satisfies(Some(stack))
+            && optimizer_modes.is_none_or(|modes| {
+                modes.contains(
+                    self.resolc_optimizer.mode.unwrap_or(ResolcOptimizerMode::RESOLC_DEFAULT),
+                )
+            })
+    }
+}
+
+/// A set of `resolc` optimizer modes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ResolcOptimizerModes(u8);
+
+impl ResolcOptimizerModes {
+    fn bit(mode: ResolcOptimizerMode) -> u8 {
+        1 << mode as u8
+    }
+
+    /// Returns true if the set contains the given mode.
+    pub fn contains(&self, mode: ResolcOptimizerMode) -> bool {
+        self.0 & Self::bit(mode) != 0
+    }
+
+    /// Adds the given mode to the set.
+    pub fn insert(&mut self, mode: ResolcOptimizerMode) {
+        self.0 |= Self::bit(mode);
+    }
+
+    /// Returns true if the set contains no modes.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the modes contained in both sets.
+    pub fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Returns an iterator over the modes in the set.
+    pub fn iter(&self) -> impl Iterator<Item = ResolcOptimizerMode> + '_ {
+        ResolcOptimizerMode::ALL.into_iter().filter(|mode| self.contains(*mode))
+    }
+}
+
+impl FromIterator<ResolcOptimizerMode> for ResolcOptimizerModes {
+    fn from_iter<T: IntoIterator<Item = ResolcOptimizerMode>>(iter: T) -> Self {
+        let mut modes = Self::default();
+        for mode in iter {
+            modes.insert(mode);
+        }
+        modes
+    }
+}
+
+/// Restrictions on settings for the resolc compiler.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ResolcRestrictions {
+    pub heap_size: Restriction<u32>,
+    pub stack_size: Restriction<u32>,
+    /// The allowed optimizer modes.
+    pub optimizer_modes: Option<ResolcOptimizerModes>,
+}
+
+impl CompilerSettingsRestrictions for ResolcRestrictions {
+    fn merge(self, other: Self) -> Option<Self> {
+        let optimizer_modes = match (self.optimizer_modes, other.optimizer_modes) {
+            (Some(modes), Some(other_modes)) => {
+                let modes = modes.intersection(other_modes);
+                if modes.is_empty() {
+                    return None;
+                }
+                Some(modes)
+            }
+            (modes, other_modes) => modes.or(other_modes),
+        };
+
+        Some(Self {
+            heap_size: self.heap_size.merge(other.heap_size)?,
+            stack_size: self.stack_size.merge(other.stack_size)?,
+            optimizer_modes,
+        })
     }
 }
 
@@ -92,6 +287,52 @@ pub struct ResolcVersionedInput {
     #[serde(flatten)]
     pub cli_settings: CliSettings,
     pub solc_version: Version,
+    /// The limits the compiled contracts are checked against, not passed to `resolc`.
+    #[serde(skip)]
+    pub limits: PolkaVmLimits,
+    /// The requested outputs `resolc` doesn't emit, which were removed from the input.
+    #[serde(skip)]
+    pub unsupported_outputs: BTreeSet<String>,
+    /// The `resolc` version required by the settings and the sources, not passed to `resolc`.
+    #[serde(skip)]
+    pub resolc_version_req: Option<VersionReq>,
+}
+
+/// The exact standard JSON input fed to `resolc`, together with the versions of `resolc` and
+/// `solc` it was fed to.
+///
+/// This is everything needed to reproduce a PolkaVM build, e.g. to verify a contract.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolcStandardJsonInput {
+    pub resolc_version: Version,
+    pub solc_version: Version,
+    pub input: ResolcInput,
+}
+
+impl ResolcStandardJsonInput {
+    /// Reproduces the input of the `resolc` run recorded in the given build info.
+    ///
+    /// The build info has to contain the full input, see
+    /// [`Project::build_info`](crate::Project::build_info), and has to be produced by `resolc`.
+    pub fn from_build_info<L>(build_info: &RawBuildInfo<L>) -> Result<Self> {
+        let resolc_version = build_info
+            .build_context
+            .resolc_version
+            .clone()
+            .ok_or_else(|| SolcError::msg("build info wasn't produced by resolc"))?;
+        let input = build_info
+            .build_info
+            .get("input")
+            .ok_or_else(|| SolcError::msg("build info doesn't contain the input"))?;
+        // `Resolc` records its own input, the `MultiCompiler` records the `solc` input it converts
+        let input = if input.get("solc_version").is_some() {
+            serde_json::from_value::<ResolcVersionedInput>(input.clone())?
+        } else {
+            serde_json::from_value::<SolcVersionedInput>(input.clone())?.into()
+        };
+        Ok(Self { resolc_version, solc_version: input.solc_version, input: input.input })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -121,21 +362,6 @@ impl CompilerInput for ResolcVersionedInput {
         language: Self::Language,
         version: Version,
     ) -> Self {
-        let hash_set = HashSet::from([
-            "abi",
-            "metadata",
-            "devdoc",
-            "userdoc",
-            "evm.methodIdentifiers",
-            "storageLayout",
-            "ast",
-            "irOptimized",
-            "evm.legacyAssembly",
-            "evm.bytecode",
-            "evm.deployedBytecode",
-            "evm.assembly",
-            "ir",
-        ]);
         let solc_settings = settings.settings.sanitized(&version, language);
 
         let mut settings = Self::Settings {
@@ -144,16 +370,20 @@ impl CompilerInput for ResolcVersionedInput {
             extra_settings: settings.extra_settings,
         };
 
-        settings.update_output_selection(|selection| {
-            for (_, key) in selection.0.iter_mut() {
-                for (_, value) in key.iter_mut() {
-                    value.retain(|item| hash_set.contains(item.as_str()));
-                }
-            }
-        });
+        let unsupported_outputs =
+            Resolc::OUTPUT_CAPABILITIES.sanitize(&mut settings.settings.output_selection);
         let cli_settings = settings.cli_settings.clone();
+        let limits = settings.extra_settings.limits;
+        let resolc_version_req = resolc_version_req(&sources, &settings.extra_settings);
         let input = ResolcInput::new(language, sources, settings);
-        Self { input, cli_settings, solc_version: version }
+        Self {
+            input,
+            cli_settings,
+            solc_version: version,
+            limits,
+            unsupported_outputs,
+            resolc_version_req,
+        }
     }
 
     fn language(&self) -> Self::Language {
@@ -173,6 +403,47 @@ impl CompilerInput for ResolcVersionedInput {
     }
 }
 
+impl From<SolcVersionedInput> for ResolcVersionedInput {
+    fn from(input: SolcVersionedInput) -> Self {
+        Self::build(
+            input.input.sources,
+            SolcSettings {
+                settings: input.input.settings,
+                cli_settings: input.cli_settings,
+                extra_settings: input.extra_settings,
+            },
+            input.input.language,
+            input.version,
+        )
+    }
+}
+
+/// Combines the `resolc` version required by the settings with the `// pragma resolc` comments of
+/// the sources.
+fn resolc_version_req(sources: &Sources, settings: &ResolcSettings) -> Option<VersionReq> {
+    let pragmas = sources
+        .values()
+        .filter_map(|source| {
+            find_resolc_version_pragma(&source.content)
+                .and_then(|version| SolData::parse_version_req(version.as_str()).ok())
+        })
+        .collect::<Vec<_>>();
+    combine_resolc_version_reqs(settings.resolc_version.iter().chain(&pragmas))
+}
+
+/// Combines the comparators of the `resolc` version requirements, skipping duplicates.
+pub(crate) fn combine_resolc_version_reqs<'a>(
+    reqs: impl IntoIterator<Item = &'a VersionReq>,
+) -> Option<VersionReq> {
+    let mut comparators = Vec::new();
+    for comparator in reqs.into_iter().flat_map(|req| &req.comparators) {
+        if !comparators.contains(comparator) {
+            comparators.push(comparator.clone());
+        }
+    }
+    (!comparators.is_empty()).then_some(VersionReq { comparators })
+}
+
 impl ResolcInput {
     fn new(language: SolcLanguage, sources: Sources, settings: SolcSettings) -> Self {
         Self { language, sources, settings: settings.into() }
@@ -257,7 +528,7 @@ impl From<SolcSettings> for ResolcSettingsInput {
                     libraries,
                     eof_version,
                 },
-            extra_settings: ResolcSettings { polkavm, resolc_optimizer },
+            extra_settings: ResolcSettings { polkavm, resolc_optimizer, .. },
             ..
         } = settings;
 
@@ -343,7 +614,7 @@ pub struct ResolcOptimizerInput {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub runs: Option<usize>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub mode: Option<char>,
+    pub mode: Option<ResolcOptimizerMode>,
     /// Switch optimizer components on or off in detail.
     /// The "enabled" switch above provides two defaults which can be
     /// tweaked here. If "details" is given, "enabled" can be omitted.
@@ -376,3 +647,158 @@ impl Default for ResolcSettingsInput {
         .with_ast()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{buildinfo::ToolchainVersions, CompilerOutput};
+    use foundry_compilers_artifacts::{Contract, Error};
+
+    #[test]
+    fn optimizer_mode_serde_roundtrip() {
+        for mode in ResolcOptimizerMode::ALL {
+            let json = serde_json::to_string(&mode).unwrap();
+            assert_eq!(json, format!("\"{}\"", mode.as_char()));
+            assert_eq!(serde_json::from_str::<ResolcOptimizerMode>(&json).unwrap(), mode);
+        }
+
+        let optimizer: ResolcOptimizer = serde_json::from_str(r#"{"mode":"z"}"#).unwrap();
+        assert_eq!(optimizer.mode, Some(ResolcOptimizerMode::MinSize));
+        assert!(serde_json::from_str::<ResolcOptimizer>(r#"{"mode":"x"}"#).is_err());
+    }
+
+    #[test]
+    fn validates_optimizer_mode() {
+        let settings = ResolcSettings::new(Some('s'), None, None, None).unwrap();
+        assert_eq!(settings.resolc_optimizer.mode, Some(ResolcOptimizerMode::Size));
+        assert!(ResolcSettings::new(None, None, None, None)
+            .unwrap()
+            .resolc_optimizer
+            .mode
+            .is_none());
+
+        let err = ResolcSettings::new(Some('4'), None, None, None).unwrap_err();
+        assert!(matches!(err, SolcError::InvalidResolcOptimizerMode('4')), "{err}");
+    }
+
+    #[test]
+    fn can_combine_resolc_version_reqs() {
+        let mut settings = SolcSettings::default();
+        settings.extra_settings.resolc_version = Some(">=0.3.0".parse().unwrap());
+        let sources = Sources::from([
+            ("A.sol".into(), Source::new("// pragma resolc >=0.3.0;\ncontract A {}")),
+            ("B.sol".into(), Source::new("// pragma resolc <0.5.0;\ncontract B {}")),
+            ("C.sol".into(), Source::new("contract C {}")),
+        ]);
+
+        let input = ResolcVersionedInput::build(
+            sources,
+            settings,
+            SolcLanguage::Solidity,
+            Version::new(0, 8, 28),
+        );
+        let req = input.resolc_version_req.unwrap();
+        assert_eq!(req.to_string(), ">=0.3.0, <0.5.0");
+        assert!(req.matches(&Version::new(0, 4, 0)));
+        assert!(!req.matches(&Version::new(0, 5, 0)));
+
+        let input = ResolcVersionedInput::build(
+            Sources::default(),
+            SolcSettings::default(),
+            SolcLanguage::Solidity,
+            Version::new(0, 8, 28),
+        );
+        assert!(input.resolc_version_req.is_none());
+    }
+
+    #[test]
+    fn reports_unsupported_outputs() {
+        let mut settings = SolcSettings::default();
+        settings.settings.output_selection = serde_json::from_value(serde_json::json!({
+            "*": {
+                "": ["ast"],
+                "*": [
+                    "abi",
+                    "asm",
+                    "evm.bytecode.object",
+                    "evm.bytecode.sourceMap",
+                    "evm.gasEstimates",
+                    "evm.bytecode.opcodes"
+                ]
+            }
+        }))
+        .unwrap();
+
+        let input = ResolcVersionedInput::build(
+            Sources::default(),
+            settings,
+            SolcLanguage::Solidity,
+            Version::new(0, 8, 28),
+        );
+        // the default outputs aren't reported
+        assert_eq!(
+            input.unsupported_outputs,
+            BTreeSet::from(["evm.bytecode.opcodes".to_string(), "evm.gasEstimates".to_string()])
+        );
+        let selection = &input.input.settings.output_selection.as_ref()["*"];
+        assert_eq!(selection[""], ["ast"]);
+        assert_eq!(selection["*"], ["abi", "evm.assembly"]);
+
+        let input = ResolcVersionedInput::build(
+            Sources::default(),
+            SolcSettings::default(),
+            SolcLanguage::Solidity,
+            Version::new(0, 8, 28),
+        );
+        assert!(input.unsupported_outputs.is_empty());
+    }
+
+    #[test]
+    fn can_reproduce_input_from_build_info() {
+        let settings = SolcSettings {
+            extra_settings: ResolcSettings::new(Some('z'), None, None, None).unwrap(),
+            ..Default::default()
+        };
+        let input = SolcVersionedInput::build(
+            Sources::from([("A.sol".into(), Source::new("contract A {}"))]),
+            settings,
+            SolcLanguage::Solidity,
+            Version::new(0, 8, 28),
+        );
+        let resolc_version = Version::parse("0.1.0-dev.16").unwrap();
+        let output = CompilerOutput::<Error, Contract> {
+            toolchain: ToolchainVersions {
+                resolc_version: Some(resolc_version.clone()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let expected = ResolcVersionedInput::from(input.clone());
+
+        let build_info = RawBuildInfo::new(&input, &output, &resolc_version, true).unwrap();
+        let reproduced = ResolcStandardJsonInput::from_build_info(&build_info).unwrap();
+        assert_eq!(reproduced.resolc_version, resolc_version);
+        assert_eq!(reproduced.solc_version, expected.solc_version);
+        assert_eq!(reproduced.input.settings.optimizer.mode, Some(ResolcOptimizerMode::MinSize));
+        assert_eq!(
+            serde_json::to_value(&reproduced.input).unwrap(),
+            serde_json::to_value(&expected.input).unwrap()
+        );
+
+        // `Resolc` records the converted input
+        let build_info = RawBuildInfo::new(&expected, &output, &resolc_version, true).unwrap();
+        let reproduced = ResolcStandardJsonInput::from_build_info(&build_info).unwrap();
+        assert_eq!(
+            serde_json::to_value(&reproduced.input).unwrap(),
+            serde_json::to_value(&expected.input).unwrap()
+        );
+
+        // the input isn't recorded without full build info
+        let build_info = RawBuildInfo::new(&input, &output, &resolc_version, false).unwrap();
+        assert!(ResolcStandardJsonInput::from_build_info(&build_info).is_err());
+        // `solc` builds can't be reproduced with `resolc`
+        let output = CompilerOutput::<Error, Contract>::default();
+        let build_info = RawBuildInfo::new(&input, &output, &input.version, true).unwrap();
+        assert!(ResolcStandardJsonInput::from_build_info(&build_info).is_err());
+    }
+}
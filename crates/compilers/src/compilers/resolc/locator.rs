@@ -0,0 +1,263 @@
+use super::Resolc;
+use crate::solc::SolcCompiler;
+use semver::Version;
+use std::{
+    env,
+    ffi::{OsStr, OsString},
+    path::{Path, PathBuf},
+};
+
+/// Locates `resolc` binaries that are not managed by `rvm`, e.g. binaries vendored in a repository
+/// or installed by a package manager.
+///
+/// Every candidate is probed via [`Resolc::new`], which queries `--version` and
+/// `--supported-solc-versions`. Candidates are searched in the following order:
+///  1. the directories added via [`ResolcLocator::dir`]
+///  2. the paths in the configured environment variable, [`ResolcLocator::DEFAULT_ENV`] by default
+///  3. the directories in `PATH`, unless disabled via [`ResolcLocator::search_path`]
+///
+/// Inside a directory every file whose name starts with `resolc` is a candidate, so multiple
+/// versions can be kept side by side, e.g. `resolc-0.1.0-dev.13` and `resolc-0.1.0-dev.16`.
+#[derive(Clone, Debug)]
+pub struct ResolcLocator {
+    dirs: Vec<PathBuf>,
+    env: Option<String>,
+    search_path: bool,
+}
+
+impl Default for ResolcLocator {
+    fn default() -> Self {
+        Self { dirs: Vec::new(), env: Some(Self::DEFAULT_ENV.to_string()), search_path: true }
+    }
+}
+
+impl ResolcLocator {
+    /// The default environment variable, containing `resolc` binaries or directories of binaries
+    /// separated like `PATH` entries.
+    pub const DEFAULT_ENV: &'static str = "RESOLC_PATH";
+
+    /// Creates a locator that doesn't search anywhere.
+    pub fn empty() -> Self {
+        Self { dirs: Vec::new(), env: None, search_path: false }
+    }
+
+    /// Adds a directory to search for `resolc` binaries.
+    #[must_use]
+    pub fn dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dirs.push(dir.into());
+        self
+    }
+
+    /// Adds multiple directories to search for `resolc` binaries.
+    #[must_use]
+    pub fn dirs(mut self, dirs: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+        self.dirs.extend(dirs.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets the environment variable to read `resolc` binaries or directories from.
+    ///
+    /// Passing `None` disables the lookup.
+    #[must_use]
+    pub fn env(mut self, env: Option<impl Into<String>>) -> Self {
+        self.env = env.map(Into::into);
+        self
+    }
+
+    /// Sets whether to search the directories in `PATH`.
+    #[must_use]
+    pub fn search_path(mut self, search_path: bool) -> Self {
+        self.search_path = search_path;
+        self
+    }
+
+    /// Returns all candidate binaries, in search order and without duplicates.
+    pub fn candidates(&self) -> Vec<PathBuf> {
+        self.candidates_in(
+            self.env.as_ref().and_then(env::var_os),
+            self.search_path.then(|| env::var_os("PATH")).flatten(),
+        )
+    }
+
+    /// Returns all candidate binaries, using the given values of the configured environment
+    /// variable and `PATH`.
+    fn candidates_in(&self, env_paths: Option<OsString>, path: Option<OsString>) -> Vec<PathBuf> {
+        let mut candidates = Vec::new();
+
+        for dir in &self.dirs {
+            scan_dir(dir, &mut candidates);
+        }
+
+        if let Some(paths) = env_paths {
+            for path in env::split_paths(&paths) {
+                if path.is_dir() {
+                    scan_dir(&path, &mut candidates);
+                } else if path.is_file() {
+                    candidates.push(path);
+                }
+            }
+        }
+
+        if let Some(paths) = path.filter(|_| self.search_path) {
+            for dir in env::split_paths(&paths) {
+                scan_dir(&dir, &mut candidates);
+            }
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        candidates.retain(|path| seen.insert(path.clone()));
+        candidates
+    }
+
+    /// Returns all candidates that could be probed successfully.
+    pub fn find_all(&self, solc_compiler: SolcCompiler) -> Vec<Resolc> {
+        probe_all(self.candidates(), solc_compiler)
+    }
+
+    /// Returns the first candidate with the given version that supports the `solc` compiler.
+    ///
+    /// Build metadata of the version is ignored.
+    pub fn find(&self, resolc_version: &Version, solc_compiler: SolcCompiler) -> Option<Resolc> {
+        let solc_version = match &solc_compiler {
+            SolcCompiler::Specific(solc) => Some(solc.version_short()),
+            #[cfg(feature = "svm-solc")]
+            SolcCompiler::AutoDetect => None,
+        };
+
+        self.candidates().into_iter().find_map(|path| {
+            let resolc = match Resolc::new(&path, solc_compiler.clone()) {
+                Ok(resolc) => resolc,
+                Err(err) => {
+                    debug!(?path, %err, "skipping resolc candidate");
+                    return None;
+                }
+            };
+            let version = &resolc.resolc_version;
+            let matches = (version.major, version.minor, version.patch, &version.pre)
+                == (
+                    resolc_version.major,
+                    resolc_version.minor,
+                    resolc_version.patch,
+                    &resolc_version.pre,
+                )
+                && solc_version.as_ref().is_none_or(|v| resolc.supported_solc_versions.matches(v));
+            matches.then_some(resolc)
+        })
+    }
+}
+
+/// Returns all candidates that could be probed successfully.
+fn probe_all(candidates: Vec<PathBuf>, solc_compiler: SolcCompiler) -> Vec<Resolc> {
+    candidates
+        .into_iter()
+        .filter_map(|path| match Resolc::new(&path, solc_compiler.clone()) {
+            Ok(resolc) => Some(resolc),
+            Err(err) => {
+                debug!(?path, %err, "skipping resolc candidate");
+                None
+            }
+        })
+        .collect()
+}
+
+/// Adds all files in `dir` whose name starts with `resolc`, sorted by name.
+fn scan_dir(dir: &Path, candidates: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else { return };
+    let mut files = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .file_name()
+                    .and_then(OsStr::to_str)
+                    .is_some_and(|name| name.starts_with("resolc"))
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    candidates.extend(files);
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::solc::Solc;
+    use std::{fs, os::unix::fs::PermissionsExt};
+
+    /// Writes a script standing in for `resolc`.
+    fn stub_resolc(dir: &Path, name: &str, version: &str) -> PathBuf {
+        let path = dir.join(name);
+        fs::write(
+            &path,
+            format!(
+                "#!/bin/sh\n\
+                 case \"$1\" in\n\
+                 --version) echo \"Solidity frontend for the revive compiler version {version}\" ;;\n\
+                 --supported-solc-versions) echo \">=0.8.0, <=0.8.29\" ;;\n\
+                 *) exit 1 ;;\n\
+                 esac\n"
+            ),
+        )
+        .unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    fn solc(version: Version) -> SolcCompiler {
+        SolcCompiler::Specific(Solc::new_with_version("solc", version))
+    }
+
+    #[test]
+    fn can_find_resolc_in_dirs() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dev13 = stub_resolc(tmp.path(), "resolc-0.1.0-dev.13", "0.1.0-dev.13+commit.a1b2c3d");
+        let dev16 = stub_resolc(tmp.path(), "resolc-0.1.0-dev.16", "0.1.0-dev.16+commit.d4e5f6a");
+        fs::write(tmp.path().join("solc"), "").unwrap();
+
+        let locator = ResolcLocator::empty().dir(tmp.path());
+        assert_eq!(locator.candidates(), [dev13, dev16.clone()]);
+
+        let version = Version::parse("0.1.0-dev.16").unwrap();
+        let resolc = locator.find(&version, solc(Version::new(0, 8, 28))).unwrap();
+        assert_eq!(resolc.resolc, dev16);
+        assert_eq!(resolc.resolc_version, version);
+        assert!(resolc.supported_solc_versions.matches(&Version::new(0, 8, 29)));
+
+        // `solc` version not supported by the binary
+        assert!(locator.find(&version, solc(Version::new(0, 7, 6))).is_none());
+        // not managed by `rvm`
+        let resolc =
+            Resolc::find_installed_with(locator.clone(), &version, solc(Version::new(0, 8, 28)))
+                .unwrap();
+        assert_eq!(resolc.unwrap().resolc, dev16);
+        // unknown version
+        assert!(locator
+            .find(&Version::parse("0.1.0-dev.20").unwrap(), solc(Version::new(0, 8, 28)))
+            .is_none());
+    }
+
+    #[test]
+    fn can_find_resolc_via_env() {
+        let tmp = tempfile::tempdir().unwrap();
+        let bin = tmp.path().join("bin");
+        fs::create_dir(&bin).unwrap();
+        let resolc = stub_resolc(&bin, "resolc", "0.1.0-dev.13");
+        let broken = tmp.path().join("resolc-broken");
+        fs::write(&broken, "#!/bin/sh\nexit 1\n").unwrap();
+        fs::set_permissions(&broken, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let env_paths = env::join_paths([&broken, &bin]).unwrap();
+        let locator = ResolcLocator::empty().env(Some("FOUNDRY_COMPILERS_TEST_RESOLC_PATH"));
+        let candidates = locator.candidates_in(Some(env_paths), None);
+        assert_eq!(candidates, [broken, resolc.clone()]);
+
+        let found = probe_all(candidates, solc(Version::new(0, 8, 28)));
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].resolc, resolc);
+
+        // `PATH` is only scanned if enabled
+        let locator = ResolcLocator::empty();
+        assert!(locator.candidates_in(None, Some(bin.clone().into_os_string())).is_empty());
+        let locator = locator.search_path(true);
+        assert_eq!(locator.candidates_in(None, Some(bin.into_os_string())), [resolc]);
+    }
+}
@@ -1,14 +1,43 @@
 mod compiler;
 mod input;
+mod locator;
+mod size;
 
+use crate::buildinfo::ToolchainVersions;
 pub use compiler::Resolc;
-use foundry_compilers_artifacts::{resolc::ResolcCompilerOutput, solc::error::Error, Contract};
-pub use input::{ResolcInput, ResolcSettings, ResolcVersionedInput};
+use foundry_compilers_artifacts::{
+    resolc::{contract::ResolcContract, ResolcCompilerOutput, MISSING_LIBRARIES_ERROR_CODE},
+    solc::error::{Error, SourceLocation},
+    Contract, Severity,
+};
+pub use input::{
+    MemoryConfig, ResolcInput, ResolcOptimizerMode, ResolcOptimizerModes, ResolcRestrictions,
+    ResolcSettings, ResolcStandardJsonInput, ResolcVersionedInput,
+};
+pub use locator::ResolcLocator;
+pub use size::{PolkaVmContractSize, PolkaVmLimits};
+use std::path::Path;
 
 impl From<ResolcCompilerOutput> for super::CompilerOutput<Error, Contract> {
     fn from(output: ResolcCompilerOutput) -> Self {
+        let mut errors = output.errors;
+        errors.extend(output.contracts.iter().flat_map(|(file, contracts)| {
+            contracts
+                .iter()
+                .filter_map(move |(name, contract)| missing_libraries_error(file, name, contract))
+        }));
+
+        let toolchain = ToolchainVersions {
+            resolc_version: None,
+            // NOTE: semver doesn't like `+` in g++ in build metadata which is invalid semver
+            solc_long_version: output
+                .long_version
+                .and_then(|version| version.replace(".g++", ".gcc").parse().ok()),
+            revive_version: output.revive_version,
+        };
+
         Self {
-            errors: output.errors,
+            errors,
             contracts: output
                 .contracts
                 .into_iter()
@@ -16,6 +45,87 @@ impl From<ResolcCompilerOutput> for super::CompilerOutput<Error, Contract> {
                 .collect(),
             sources: output.sources,
             metadata: Default::default(),
+            toolchain,
         }
     }
 }
+
+/// Creates the diagnostic for a contract that can't be deployed until the libraries it depends on
+/// are linked.
+fn missing_libraries_error(file: &Path, name: &str, contract: &ResolcContract) -> Option<Error> {
+    let mut libraries: Vec<_> = contract.missing_libraries.as_ref()?.iter().collect();
+    if libraries.is_empty() {
+        return None;
+    }
+    libraries.sort();
+
+    let file = file.to_string_lossy();
+    let message = format!(
+        "Contract \"{file}:{name}\" is missing libraries: {}. Provide their addresses via the \
+         `libraries` setting.",
+        libraries.iter().map(|lib| format!("\"{lib}\"")).collect::<Vec<_>>().join(", ")
+    );
+
+    Some(Error {
+        source_location: Some(SourceLocation { file: file.to_string(), start: -1, end: -1 }),
+        secondary_source_locations: Vec::new(),
+        r#type: "LinkerError".to_string(),
+        component: "general".to_string(),
+        severity: Severity::Error,
+        error_code: Some(MISSING_LIBRARIES_ERROR_CODE),
+        formatted_message: Some(format!("LinkerError: {message}")),
+        message,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compilers::CompilerOutput;
+    use std::collections::{BTreeMap, HashSet};
+
+    #[test]
+    fn reports_missing_libraries() {
+        let contract = ResolcContract {
+            missing_libraries: Some(HashSet::from(["src/Math.sol:Math".to_string()])),
+            ..Default::default()
+        };
+        let output = ResolcCompilerOutput {
+            long_version: Some("0.8.28+commit.7893614a.Linux.g++".to_string()),
+            revive_version: Some("1.0.0".to_string()),
+            contracts: BTreeMap::from([
+                ("src/Linked.sol".into(), BTreeMap::from([("Linked".to_string(), contract)])),
+                (
+                    "src/Math.sol".into(),
+                    BTreeMap::from([("Math".to_string(), ResolcContract::default())]),
+                ),
+            ]),
+            ..Default::default()
+        };
+
+        let output: CompilerOutput<Error, Contract> = output.into();
+
+        assert_eq!(output.errors.len(), 1);
+        let error = &output.errors[0];
+        assert!(error.is_error());
+        assert_eq!(error.error_code, Some(MISSING_LIBRARIES_ERROR_CODE));
+        assert_eq!(error.source_location.as_ref().unwrap().file, "src/Linked.sol");
+        assert!(error.message.contains("\"src/Math.sol:Math\""));
+        assert_eq!(
+            output.toolchain.solc_long_version,
+            Some("0.8.28+commit.7893614a.Linux.gcc".parse().unwrap())
+        );
+        assert_eq!(output.toolchain.revive_version.as_deref(), Some("1.0.0"));
+        assert_eq!(
+            output.contracts[Path::new("src/Linked.sol")]["Linked"].missing_libraries,
+            Some(BTreeMap::from([(
+                "src/Math.sol".to_string(),
+                ["Math".to_string()].into_iter().collect()
+            )]))
+        );
+
+        // `resolc` doesn't report the `solc` version for Yul inputs
+        let output: CompilerOutput<Error, Contract> = ResolcCompilerOutput::default().into();
+        assert_eq!(output.toolchain, ToolchainVersions::default());
+    }
+}
@@ -0,0 +1,234 @@
+use super::input::MemoryConfig;
+use crate::compilers::{CompilerContract, CompilerOutput};
+use foundry_compilers_artifacts::{
+    resolc::{
+        is_polkavm_blob, BLOB_SIZE_ERROR_CODE, INITCODE_SIZE_ERROR_CODE, MEMORY_BUDGET_ERROR_CODE,
+    },
+    solc::error::{Error, SourceLocation},
+    Contract, Severity,
+};
+use serde::{Deserialize, Serialize};
+
+/// The code sizes of a PolkaVM contract and the memory it was compiled with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PolkaVmContractSize {
+    /// The size of the PolkaVM blob stored on chain, in bytes.
+    pub blob_size: usize,
+    /// The size of the code submitted when instantiating the contract, in bytes.
+    pub initcode_size: usize,
+    /// The configured heap size, in bytes.
+    pub heap_size: u32,
+    /// The configured stack size, in bytes.
+    pub stack_size: u32,
+}
+
+impl PolkaVmContractSize {
+    /// Returns the sizes of the contract with the given initcode and blob.
+    ///
+    /// Returns `None` if the blob is not a PolkaVM blob, e.g. for EVM contracts.
+    pub fn new(initcode: &[u8], blob: &[u8], memory_config: &MemoryConfig) -> Option<Self> {
+        is_polkavm_blob(blob).then_some(Self {
+            blob_size: blob.len(),
+            initcode_size: initcode.len(),
+            heap_size: memory_config.heap_size,
+            stack_size: memory_config.stack_size,
+        })
+    }
+
+    /// Returns the sizes of a compiled contract, if it is a PolkaVM contract.
+    pub fn from_contract(contract: &Contract, memory_config: &MemoryConfig) -> Option<Self> {
+        let blob = contract.bin_runtime_ref()?.as_bytes()?;
+        let initcode = contract.bin_ref().and_then(|code| code.as_bytes()).unwrap_or(blob);
+        Self::new(initcode, blob, memory_config)
+    }
+
+    /// Returns the memory the contract needs at runtime: the blob, the heap and the stack.
+    pub fn memory_size(&self) -> u64 {
+        self.blob_size as u64 + self.heap_size as u64 + self.stack_size as u64
+    }
+}
+
+/// Limits PolkaVM contracts are checked against after compilation, similar to the EIP-170 code
+/// size limit on EVM chains.
+///
+/// Every exceeded limit is reported as a diagnostic with the configured severity, so it can be
+/// ignored via its error code like any other compiler diagnostic. Unset limits are not checked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolkaVmLimits {
+    /// The maximum size of the PolkaVM blob, in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_blob_size: Option<usize>,
+    /// The maximum size of the initcode, in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_initcode_size: Option<usize>,
+    /// The maximum memory of the blob, the heap and the stack combined, in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_memory_size: Option<u64>,
+    /// The severity of the reported diagnostics.
+    #[serde(default = "default_severity")]
+    pub severity: Severity,
+}
+
+fn default_severity() -> Severity {
+    Severity::Warning
+}
+
+impl Default for PolkaVmLimits {
+    fn default() -> Self {
+        Self {
+            max_blob_size: None,
+            max_initcode_size: None,
+            max_memory_size: None,
+            severity: default_severity(),
+        }
+    }
+}
+
+impl PolkaVmLimits {
+    /// Returns true if no limit is set.
+    pub fn is_empty(&self) -> bool {
+        self.max_blob_size.is_none()
+            && self.max_initcode_size.is_none()
+            && self.max_memory_size.is_none()
+    }
+
+    /// Returns the error code and a description of every limit exceeded by the contract.
+    pub fn violations(&self, size: &PolkaVmContractSize) -> Vec<(u64, String)> {
+        let mut violations = Vec::new();
+        if let Some(max) = self.max_blob_size.filter(|max| size.blob_size > *max) {
+            violations.push((
+                BLOB_SIZE_ERROR_CODE,
+                format!("blob size of {} bytes exceeds the limit of {max} bytes", size.blob_size),
+            ));
+        }
+        if let Some(max) = self.max_initcode_size.filter(|max| size.initcode_size > *max) {
+            violations.push((
+                INITCODE_SIZE_ERROR_CODE,
+                format!(
+                    "initcode size of {} bytes exceeds the limit of {max} bytes",
+                    size.initcode_size
+                ),
+            ));
+        }
+        if let Some(max) = self.max_memory_size.filter(|max| size.memory_size() > *max) {
+            violations.push((
+                MEMORY_BUDGET_ERROR_CODE,
+                format!(
+                    "memory of {} bytes (blob: {}, heap: {}, stack: {}) exceeds the budget of \
+                     {max} bytes",
+                    size.memory_size(),
+                    size.blob_size,
+                    size.heap_size,
+                    size.stack_size
+                ),
+            ));
+        }
+        violations
+    }
+
+    /// Appends a diagnostic for every limit exceeded by a contract of the output.
+    pub(crate) fn check_output(
+        &self,
+        output: &mut CompilerOutput<Error, Contract>,
+        memory_config: &MemoryConfig,
+    ) {
+        if self.is_empty() {
+            return;
+        }
+
+        let mut errors = Vec::new();
+        for (file, contracts) in &output.contracts {
+            for (name, contract) in contracts {
+                let Some(size) = PolkaVmContractSize::from_contract(contract, memory_config) else {
+                    continue;
+                };
+                let file = file.to_string_lossy();
+                for (code, violation) in self.violations(&size) {
+                    let message = format!("Contract \"{file}:{name}\": {violation}.");
+                    errors.push(Error {
+                        source_location: Some(SourceLocation {
+                            file: file.to_string(),
+                            start: -1,
+                            end: -1,
+                        }),
+                        secondary_source_locations: Vec::new(),
+                        r#type: self.severity.to_string(),
+                        component: "general".to_string(),
+                        severity: self.severity,
+                        error_code: Some(code),
+                        formatted_message: Some(format!("{}: {message}", self.severity)),
+                        message,
+                    });
+                }
+            }
+        }
+        output.errors.extend(errors);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{collections::BTreeMap, path::Path};
+
+    fn contract(code: &[u8]) -> Contract {
+        let object = alloy_primitives::hex::encode_prefixed(code);
+        serde_json::from_value(serde_json::json!({
+            "evm": {
+                "bytecode": { "object": object },
+                "deployedBytecode": { "object": object }
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn reports_exceeded_limits() {
+        let memory_config = MemoryConfig { heap_size: 64, stack_size: 32 };
+        let mut output = CompilerOutput {
+            errors: Vec::new(),
+            contracts: BTreeMap::from([(
+                "src/Large.sol".into(),
+                BTreeMap::from([
+                    ("Large".to_string(), contract(&[b"PVM\0".as_slice(), &[0; 96]].concat())),
+                    ("Evm".to_string(), contract(&[0x60; 200])),
+                ]),
+            )]),
+            sources: Default::default(),
+            metadata: Default::default(),
+            toolchain: Default::default(),
+        };
+
+        let size = PolkaVmContractSize::from_contract(
+            &output.contracts[Path::new("src/Large.sol")]["Large"],
+            &memory_config,
+        )
+        .unwrap();
+        assert_eq!(size.blob_size, 100);
+        assert_eq!(size.initcode_size, 100);
+        assert_eq!(size.memory_size(), 196);
+
+        PolkaVmLimits::default().check_output(&mut output, &memory_config);
+        assert!(output.errors.is_empty());
+
+        let limits = PolkaVmLimits {
+            max_blob_size: Some(100),
+            max_initcode_size: Some(99),
+            max_memory_size: Some(128),
+            severity: Severity::Error,
+        };
+        limits.check_output(&mut output, &memory_config);
+        let codes = output.errors.iter().map(|err| err.error_code.unwrap()).collect::<Vec<_>>();
+        assert_eq!(codes, [INITCODE_SIZE_ERROR_CODE, MEMORY_BUDGET_ERROR_CODE]);
+        assert!(output.errors.iter().all(|err| err.is_error()));
+        assert_eq!(output.errors[0].source_location.as_ref().unwrap().file, "src/Large.sol");
+        assert!(output.errors[1].message.contains("heap: 64, stack: 32"));
+    }
+
+    #[test]
+    fn can_deserialize_limits() {
+        let limits: PolkaVmLimits = serde_json::from_str(r#"{"max_blob_size": 1024}"#).unwrap();
+        assert_eq!(limits.max_blob_size, Some(1024));
+        assert_eq!(limits.severity, Severity::Warning);
+    }
+}
@@ -1,6 +1,8 @@
 use super::{
-    resolc::ResolcSettings, restrictions::CompilerSettingsRestrictions, Compiler, CompilerInput,
-    CompilerOutput, CompilerSettings, CompilerVersion, Language, ParsedSource, SimpleCompilerName,
+    resolc::{ResolcRestrictions, ResolcSettings},
+    restrictions::CompilerSettingsRestrictions,
+    CompilationTarget, Compiler, CompilerInput, CompilerOutput, CompilerSettings, CompilerVersion,
+    Language, ParsedSource, SimpleCompilerName,
 };
 use crate::{resolver::parse::SolData, CompilationError};
 pub use foundry_compilers_artifacts::SolcLanguage;
@@ -33,6 +35,11 @@ pub enum SolcCompiler {
     Specific(Solc),
 }
 
+impl SolcCompiler {
+    /// The [`Compiler::job_key`] of `solc`.
+    pub const JOB_KEY: &'static str = "solc";
+}
+
 impl Language for SolcLanguage {
     const FILE_EXTENSIONS: &'static [&'static str] = SOLC_EXTENSIONS;
 }
@@ -59,6 +66,10 @@ impl Compiler for SolcCompiler {
         Self::compiler_name_default()
     }
 
+    fn job_key(&self, _input: &Self::Input) -> Cow<'static, str> {
+        Self::JOB_KEY.into()
+    }
+
     fn compile(
         &self,
         input: &Self::Input,
@@ -81,6 +92,7 @@ impl Compiler for SolcCompiler {
             contracts: solc_output.contracts,
             sources: solc_output.sources,
             metadata: BTreeMap::new(),
+            toolchain: Default::default(),
         };
 
         Ok(output)
@@ -209,6 +221,42 @@ impl DerefMut for SolcSettings {
     }
 }
 
+impl SolcSettings {
+    /// Returns true if the `solc` settings of the given `other` config are compatible with this
+    /// config, ignoring the `resolc` specific settings.
+    fn can_use_cached_solc(&self, other: &Self) -> bool {
+        let Self {
+            settings:
+                Settings {
+                    stop_after,
+                    remappings,
+                    optimizer,
+                    model_checker,
+                    metadata,
+                    output_selection,
+                    evm_version,
+                    via_ir,
+                    debug,
+                    libraries,
+                    eof_version,
+                },
+            ..
+        } = self;
+
+        *stop_after == other.settings.stop_after
+            && *remappings == other.settings.remappings
+            && *optimizer == other.settings.optimizer
+            && *model_checker == other.settings.model_checker
+            && *metadata == other.settings.metadata
+            && *evm_version == other.settings.evm_version
+            && *via_ir == other.settings.via_ir
+            && *debug == other.settings.debug
+            && *libraries == other.settings.libraries
+            && *eof_version == other.settings.eof_version
+            && output_selection.is_subset_of(&other.settings.output_selection)
+    }
+}
+
 /// Abstraction over min/max restrictions on some value.
 #[derive(Debug, Clone, Copy, Eq, Default, PartialEq)]
 pub struct Restriction<V> {
@@ -264,6 +312,8 @@ pub struct SolcRestrictions {
     pub via_ir: Option<bool>,
     pub optimizer_runs: Restriction<usize>,
     pub bytecode_hash: Option<BytecodeHash>,
+    /// Restrictions on the `resolc` specific settings.
+    pub resolc: ResolcRestrictions,
 }
 
 impl CompilerSettingsRestrictions for SolcRestrictions {
@@ -287,6 +337,7 @@ impl CompilerSettingsRestrictions for SolcRestrictions {
             via_ir: self.via_ir.or(other.via_ir),
             optimizer_runs: self.optimizer_runs.merge(other.optimizer_runs)?,
             bytecode_hash: self.bytecode_hash.or(other.bytecode_hash),
+            resolc: self.resolc.merge(other.resolc)?,
         })
     }
 }
@@ -299,37 +350,16 @@ impl CompilerSettings for SolcSettings {
     }
 
     fn can_use_cached(&self, other: &Self) -> bool {
-        let Self {
-            settings:
-                Settings {
-                    stop_after,
-                    remappings,
-                    optimizer,
-                    model_checker,
-                    metadata,
-                    output_selection,
-                    evm_version,
-                    via_ir,
-                    debug,
-                    libraries,
-                    eof_version,
-                },
-            extra_settings,
-            ..
-        } = self;
+        self.can_use_cached_solc(other) && self.extra_settings.can_use_cached(&other.extra_settings)
+    }
 
-        *stop_after == other.settings.stop_after
-            && *remappings == other.settings.remappings
-            && *optimizer == other.settings.optimizer
-            && *model_checker == other.settings.model_checker
-            && *metadata == other.settings.metadata
-            && *evm_version == other.settings.evm_version
-            && *via_ir == other.settings.via_ir
-            && *debug == other.settings.debug
-            && *libraries == other.settings.libraries
-            && *eof_version == other.settings.eof_version
-            && output_selection.is_subset_of(&other.settings.output_selection)
-            && *extra_settings == other.extra_settings
+    /// Only artifacts compiled by `resolc`, i.e. for PolkaVM, depend on the `resolc` specific
+    /// settings.
+    fn can_use_cached_target(&self, other: &Self, target: CompilationTarget) -> bool {
+        match target {
+            CompilationTarget::Evm => self.can_use_cached_solc(other),
+            CompilationTarget::PolkaVm => self.can_use_cached(other),
+        }
     }
 
     fn with_remappings(mut self, remappings: &[Remapping]) -> Self {
@@ -356,7 +386,8 @@ impl CompilerSettings for SolcSettings {
     fn satisfies_restrictions(&self, restrictions: &Self::Restrictions) -> bool {
         let mut satisfies = true;
 
-        let SolcRestrictions { evm_version, via_ir, optimizer_runs, bytecode_hash } = restrictions;
+        let SolcRestrictions { evm_version, via_ir, optimizer_runs, bytecode_hash, resolc } =
+            restrictions;
 
         satisfies &= evm_version.satisfies(self.evm_version);
         satisfies &= via_ir.is_none_or(|via_ir| via_ir == self.via_ir.unwrap_or_default());
@@ -370,8 +401,19 @@ impl CompilerSettings for SolcSettings {
             .min
             .is_none_or(|min| min == 0 || self.optimizer.enabled.unwrap_or_default());
 
+        satisfies &= self.extra_settings.satisfies_restrictions(resolc);
+
         satisfies
     }
+
+    fn target(&self) -> CompilationTarget {
+        self.extra_settings.target
+    }
+
+    fn with_target(mut self, target: CompilationTarget) -> Self {
+        self.extra_settings.target = target;
+        self
+    }
 }
 
 impl ParsedSource for SolData {
@@ -385,6 +427,10 @@ impl ParsedSource for SolData {
         self.version_req.as_ref()
     }
 
+    fn resolc_version_req(&self) -> Option<&semver::VersionReq> {
+        self.resolc_version_req.as_ref()
+    }
+
     fn contract_names(&self) -> &[String] {
         &self.contract_names
     }
@@ -445,8 +491,11 @@ mod tests {
     use crate::{
         buildinfo::RawBuildInfo,
         compilers::{
-            solc::{SolcCompiler, SolcVersionedInput},
-            CompilerInput,
+            resolc::{
+                ResolcOptimizerMode, ResolcOptimizerModes, ResolcRestrictions, ResolcSettings,
+            },
+            solc::{Restriction, SolcCompiler, SolcRestrictions, SolcSettings, SolcVersionedInput},
+            CompilationTarget, CompilerInput, CompilerSettings, CompilerSettingsRestrictions,
         },
         AggregatedCompilerOutput,
     };
@@ -480,6 +529,7 @@ mod tests {
             contracts: Default::default(),
             sources: Default::default(),
             metadata: Default::default(),
+            toolchain: Default::default(),
         };
 
         let v = Version::new(0, 8, 12);
@@ -494,4 +544,78 @@ mod tests {
         aggregated.extend(v, build_info, "default", out_converted);
         assert!(!aggregated.is_unchanged());
     }
+
+    #[test]
+    fn resolc_settings_only_invalidate_resolc_artifacts() {
+        let (evm, polkavm) = (CompilationTarget::Evm, CompilationTarget::PolkaVm);
+        let cached = SolcSettings::default();
+
+        let mut settings = cached.clone();
+        settings.extra_settings = ResolcSettings::new(Some('z'), Some(1024), None, None).unwrap();
+        assert!(!settings.can_use_cached(&cached));
+        assert!(settings.can_use_cached_target(&cached, evm));
+        assert!(!settings.can_use_cached_target(&cached, polkavm));
+
+        let mut settings = cached.clone();
+        settings.settings.optimizer.runs = Some(1);
+        assert!(!settings.can_use_cached_target(&cached, evm));
+        assert!(!settings.can_use_cached_target(&cached, polkavm));
+
+        // unset PolkaVM settings are equivalent to the defaults
+        let mut settings = cached.clone();
+        settings.extra_settings = ResolcSettings::new(None, None, None, Some(false)).unwrap();
+        assert!(settings.can_use_cached(&cached));
+    }
+
+    #[test]
+    fn can_restrict_resolc_settings() {
+        let heap = ResolcRestrictions {
+            heap_size: Restriction { min: Some(128 * 1024), ..Default::default() },
+            ..Default::default()
+        };
+        let size_optimized = ResolcRestrictions {
+            optimizer_modes: Some(
+                [ResolcOptimizerMode::Size, ResolcOptimizerMode::MinSize].into_iter().collect(),
+            ),
+            ..Default::default()
+        };
+        let restrictions =
+            SolcRestrictions { resolc: heap.merge(size_optimized).unwrap(), ..Default::default() };
+
+        let mut settings = SolcSettings::default();
+        assert!(!settings.satisfies_restrictions(&restrictions));
+
+        settings.extra_settings =
+            ResolcSettings::new(Some('z'), Some(64 * 1024), None, None).unwrap();
+        assert!(!settings.satisfies_restrictions(&restrictions));
+
+        settings.extra_settings =
+            ResolcSettings::new(Some('3'), Some(128 * 1024), None, None).unwrap();
+        assert!(!settings.satisfies_restrictions(&restrictions));
+
+        settings.extra_settings =
+            ResolcSettings::new(Some('z'), Some(128 * 1024), None, None).unwrap();
+        assert!(settings.satisfies_restrictions(&restrictions));
+
+        // an unset optimizer mode is resolc's default
+        let performance = SolcRestrictions {
+            resolc: ResolcRestrictions {
+                optimizer_modes: Some([ResolcOptimizerMode::Aggressive].into_iter().collect()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        settings.extra_settings = ResolcSettings::default();
+        assert!(settings.satisfies_restrictions(&performance));
+        assert!(!settings.satisfies_restrictions(&restrictions));
+
+        // no overlap between the allowed optimizer modes
+        let speed_optimized = ResolcRestrictions {
+            optimizer_modes: Some(ResolcOptimizerModes::from_iter([
+                ResolcOptimizerMode::Aggressive,
+            ])),
+            ..Default::default()
+        };
+        assert!(size_optimized.merge(speed_optimized).is_none());
+    }
 }
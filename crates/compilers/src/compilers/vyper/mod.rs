@@ -72,6 +72,9 @@ pub struct Vyper {
 }
 
 impl Vyper {
+    /// The [`Compiler::job_key`] of `vyper`.
+    pub const JOB_KEY: &'static str = "vyper";
+
     /// Creates a new instance of the Vyper compiler. Uses the `vyper` binary in the system `PATH`.
     pub fn new(path: impl Into<PathBuf>) -> Result<Self> {
         let path = path.into();
@@ -221,6 +224,10 @@ impl Compiler for Vyper {
         Self::compiler_name_default()
     }
 
+    fn job_key(&self, _input: &Self::Input) -> Cow<'static, str> {
+        Self::JOB_KEY.into()
+    }
+
     fn compile(
         &self,
         input: &Self::Input,
@@ -1,9 +1,7 @@
 use crate::{
     cache::SOLIDITY_FILES_CACHE_FILENAME,
-    compilers::{multi::MultiCompilerLanguage, Language},
-    flatten::{collect_ordered_deps, combine_version_pragmas},
-    resolver::{parse::SolData, SolImportAlias},
-    Graph,
+    compilers::{multi::MultiCompilerLanguage, vyper::VyperLanguage, Language},
+    flatten::{Flattener, VyperFlattened},
 };
 use foundry_compilers_artifacts::{
     output_selection::ContractOutputSelection,
@@ -96,136 +94,33 @@ impl ProjectPathsConfig<SolcLanguage> {
     /// import directives with the contents of target files. It will strip the pragma
     /// version directives and SDPX license identifiers from all imported files.
     ///
-    /// NB: the SDPX license identifier will be removed from the imported file
-    /// only if it is found at the beginning of the file.
+    /// Sources are only parsed, not compiled, see [`Flattener::new_offline`]. Definitions with the
+    /// same name in different files are renamed, and references via aliased or qualified imports
+    /// are replaced with the names of the definitions.
     pub fn flatten(&self, target: &Path) -> Result<String> {
         trace!("flattening file");
-        let mut input_files = self.input_files();
-
-        // we need to ensure that the target is part of the input set, otherwise it's not
-        // part of the graph if it's not imported by any input file
-        let flatten_target = target.to_path_buf();
-        if !input_files.contains(&flatten_target) {
-            input_files.push(flatten_target.clone());
-        }
-
-        let sources = Source::read_all_files(input_files)?;
-        let graph = Graph::<SolData>::resolve_sources(self, sources)?;
-        let ordered_deps = collect_ordered_deps(&flatten_target, self, &graph)?;
-
-        #[cfg(windows)]
-        let ordered_deps = {
-            use path_slash::PathBufExt;
-
-            let mut deps = ordered_deps;
-            for p in &mut deps {
-                *p = PathBuf::from(p.to_slash_lossy().to_string());
-            }
-            deps
-        };
-
-        let mut sources = Vec::new();
-        let mut experimental_pragma = None;
-        let mut version_pragmas = Vec::new();
-
-        let mut result = String::new();
-
-        for path in ordered_deps.iter() {
-            let node_id = *graph.files().get(path).ok_or_else(|| {
-                SolcError::msg(format!("cannot resolve file at {}", path.display()))
-            })?;
-            let node = graph.node(node_id);
-            node.data.parse_result()?;
-            let content = node.content();
-
-            // Firstly we strip all licesnses, verson pragmas
-            // We keep target file pragma and license placing them in the beginning of the result.
-            let mut ranges_to_remove = Vec::new();
-
-            if let Some(license) = &node.data.license {
-                ranges_to_remove.push(license.span());
-                if *path == flatten_target {
-                    result.push_str(&content[license.span()]);
-                    result.push('\n');
-                }
-            }
-            if let Some(version) = &node.data.version {
-                let content = &content[version.span()];
-                ranges_to_remove.push(version.span());
-                version_pragmas.push(content);
-            }
-            if let Some(experimental) = &node.data.experimental {
-                ranges_to_remove.push(experimental.span());
-                if experimental_pragma.is_none() {
-                    experimental_pragma = Some(content[experimental.span()].to_owned());
-                }
-            }
-            for import in &node.data.imports {
-                ranges_to_remove.push(import.span());
-            }
-            ranges_to_remove.sort_by_key(|loc| loc.start);
-
-            let mut content = content.as_bytes().to_vec();
-            let mut offset = 0_isize;
-
-            for range in ranges_to_remove {
-                let repl_range = utils::range_by_offset(&range, offset);
-                offset -= repl_range.len() as isize;
-                content.splice(repl_range, std::iter::empty());
-            }
-
-            let mut content = String::from_utf8(content).map_err(|err| {
-                SolcError::msg(format!("failed to convert extended bytes to string: {err}"))
-            })?;
-
-            // Iterate over all aliased imports, and replace alias with real name via regexes
-            for alias in node.data.imports.iter().flat_map(|i| i.data().aliases()) {
-                let (alias, target) = match alias {
-                    SolImportAlias::Contract(alias, target) => (alias.clone(), target.clone()),
-                    _ => continue,
-                };
-                let name_regex = utils::create_contract_or_lib_name_regex(&alias);
-                let target_len = target.len() as isize;
-                let mut replace_offset = 0;
-                for cap in name_regex.captures_iter(&content.clone()) {
-                    if cap.name("ignore").is_some() {
-                        continue;
-                    }
-                    if let Some(name_match) =
-                        ["n1", "n2", "n3"].iter().find_map(|name| cap.name(name))
-                    {
-                        let name_match_range =
-                            utils::range_by_offset(&name_match.range(), replace_offset);
-                        replace_offset += target_len - (name_match_range.len() as isize);
-                        content.replace_range(name_match_range, &target);
-                    }
-                }
-            }
-
-            let content = format!(
-                "// {}\n{}",
-                path.strip_prefix(&self.root).unwrap_or(path).display(),
-                content
-            );
-
-            sources.push(content);
-        }
-
-        if let Some(version) = combine_version_pragmas(&version_pragmas) {
-            result.push_str(&version);
-            result.push('\n');
-        }
-        if let Some(experimental) = experimental_pragma {
-            result.push_str(&experimental);
-            result.push('\n');
-        }
+        Ok(Flattener::new_offline(self, target)?.flatten())
+    }
 
-        for source in sources {
-            result.push_str("\n\n");
-            result.push_str(&source);
-        }
+    /// Flattens multiple target solidity files into a single string, like
+    /// [`ProjectPathsConfig::flatten`].
+    ///
+    /// Shared dependencies are only included once, see [`Flattener::new_offline_many`].
+    pub fn flatten_many(&self, targets: &[PathBuf]) -> Result<String> {
+        trace!("flattening files");
+        Ok(Flattener::new_offline_many(self, targets)?.flatten())
+    }
+}
 
-        Ok(format!("{}\n", utils::RE_THREE_OR_MORE_NEWLINES.replace_all(&result, "\n\n").trim()))
+impl ProjectPathsConfig<VyperLanguage> {
+    /// Prepares the target vyper file for verification.
+    ///
+    /// Vyper can't merge imported modules into a single file, so this returns the source of the
+    /// target if it only imports interfaces, which are inlined, and otherwise a standard JSON input
+    /// with the target and all files it imports. See [`VyperFlattened`].
+    pub fn flatten(&self, target: &Path) -> Result<VyperFlattened> {
+        trace!("flattening vyper file");
+        VyperFlattened::new(self, target)
     }
 }
 
@@ -5,11 +5,17 @@ use crate::{
     resolver::{parse::SolData, GraphEdges},
     Sources,
 };
-use foundry_compilers_artifacts::output_selection::OutputSelection;
+use foundry_compilers_artifacts::{output_selection::OutputSelection, serde_helpers};
+use foundry_compilers_core::error::{Result, SolcError};
+use glob::{MatchOptions, Pattern};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
 use std::{
-    collections::HashSet,
-    fmt,
+    collections::{HashMap, HashSet},
+    fmt, fs, ops,
     path::{Path, PathBuf},
+    sync::{Arc, Mutex, PoisonError},
+    time::SystemTime,
 };
 
 /// A predicate property that determines whether a file satisfies a certain condition
@@ -50,6 +56,243 @@ impl FileFilter for TestFileFilter {
     }
 }
 
+/// A [FileFilter] that matches files by glob patterns.
+///
+/// A file matches if it matches any of the `include` patterns, or there are none, and none of the
+/// `exclude` patterns. `*` does not match path separators, `**` matches any number of directories.
+///
+/// Relative patterns, like `src/**/*.sol`, are relative to the root set via
+/// [`with_root`](Self::with_root), usually the project root, and only match files inside of it.
+/// Without a root, they are matched against the path of the file as it is. Absolute patterns
+/// always have to match the entire path.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct GlobFileFilter {
+    #[serde(skip)]
+    root: Option<PathBuf>,
+    #[serde(default)]
+    include: Vec<GlobPattern>,
+    #[serde(default)]
+    exclude: Vec<GlobPattern>,
+}
+
+impl GlobFileFilter {
+    /// Creates a new filter, returning an error if any of the patterns is invalid.
+    pub fn new(
+        include: impl IntoIterator<Item = impl AsRef<str>>,
+        exclude: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            root: None,
+            include: GlobPattern::parse_all(include)?,
+            exclude: GlobPattern::parse_all(exclude)?,
+        })
+    }
+
+    /// Sets the root relative patterns are anchored to.
+    #[must_use]
+    pub fn with_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.root = Some(root.into());
+        self
+    }
+
+    fn is_pattern_match(&self, pattern: &GlobPattern, file: &Path) -> bool {
+        if Path::new(pattern.0.as_str()).is_absolute() {
+            return pattern.is_match(file);
+        }
+        match &self.root {
+            Some(root) => file.strip_prefix(root).is_ok_and(|file| pattern.is_match(file)),
+            None => pattern.is_match(file),
+        }
+    }
+}
+
+impl FileFilter for GlobFileFilter {
+    fn is_match(&self, file: &Path) -> bool {
+        (self.include.is_empty()
+            || self.include.iter().any(|pattern| self.is_pattern_match(pattern, file)))
+            && !self.exclude.iter().any(|pattern| self.is_pattern_match(pattern, file))
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+struct GlobPattern(#[serde(with = "serde_helpers::display_from_str")] Pattern);
+
+impl GlobPattern {
+    const OPTIONS: MatchOptions = MatchOptions {
+        case_sensitive: true,
+        require_literal_separator: true,
+        require_literal_leading_dot: false,
+    };
+
+    fn parse_all(patterns: impl IntoIterator<Item = impl AsRef<str>>) -> Result<Vec<Self>> {
+        patterns
+            .into_iter()
+            .map(|pattern| {
+                let pattern = pattern.as_ref();
+                Pattern::new(pattern).map(Self).map_err(|err| {
+                    SolcError::msg(format!("invalid glob pattern \"{pattern}\": {err}"))
+                })
+            })
+            .collect()
+    }
+
+    fn is_match(&self, file: &Path) -> bool {
+        self.0.matches_path_with(file, Self::OPTIONS)
+    }
+}
+
+/// A [FileFilter] that matches files defining a contract whose name matches the regex, see
+/// [`ParsedSource::contract_names`].
+///
+/// Files which can't be read or parsed never match. The contract names are cached per file until
+/// it is modified, and the cache is shared between clones of the filter.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ContractNameFilter {
+    #[serde(with = "serde_helpers::display_from_str")]
+    regex: Regex,
+    #[serde(skip)]
+    names: Arc<Mutex<HashMap<PathBuf, CachedNames>>>,
+}
+
+/// The modification time of a file and the names of the contracts it defines.
+type CachedNames = (SystemTime, Vec<String>);
+
+impl ContractNameFilter {
+    /// Creates a new filter matching contract names with the regex.
+    pub fn new(regex: Regex) -> Self {
+        Self { regex, names: Default::default() }
+    }
+}
+
+impl From<Regex> for ContractNameFilter {
+    fn from(regex: Regex) -> Self {
+        Self::new(regex)
+    }
+}
+
+impl FileFilter for ContractNameFilter {
+    fn is_match(&self, file: &Path) -> bool {
+        let Ok(modified) = fs::metadata(file).and_then(|metadata| metadata.modified()) else {
+            return false;
+        };
+        let mut names = self.names.lock().unwrap_or_else(PoisonError::into_inner);
+        if names.get(file).is_none_or(|(cached, _)| *cached != modified) {
+            let parsed = fs::read_to_string(file)
+                .ok()
+                .and_then(|content| MultiCompilerParsedSource::parse(&content, file).ok());
+            let contract_names =
+                parsed.map(|parsed| parsed.contract_names().to_vec()).unwrap_or_default();
+            names.insert(file.to_path_buf(), (modified, contract_names));
+        }
+        names[file].1.iter().any(|name| self.regex.is_match(name))
+    }
+}
+
+/// A composable [FileFilter], which can be deserialized from config.
+///
+/// Filters are represented as externally tagged values, e.g. a filter matching all sources but
+/// mocks is
+///
+/// ```json
+/// { "and": [{ "glob": { "include": ["src/**"] } }, { "not": { "contract_name": "^Mock" } }] }
+/// ```
+///
+/// Relative glob patterns are anchored to the root set via [`with_root`](Self::with_root).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SourceFilter {
+    /// Matches files by glob patterns.
+    Glob(GlobFileFilter),
+    /// Matches files whose path matches the regex.
+    PathRegex(#[serde(with = "serde_helpers::display_from_str")] Regex),
+    /// Matches files defining a contract whose name matches the regex.
+    ContractName(ContractNameFilter),
+    /// Matches files matching all of the filters.
+    And(Vec<Self>),
+    /// Matches files matching any of the filters.
+    Or(Vec<Self>),
+    /// Matches files not matching the filter.
+    Not(Box<Self>),
+}
+
+impl SourceFilter {
+    /// Returns a filter matching files matching both `self` and `other`.
+    pub fn and(self, other: impl Into<Self>) -> Self {
+        match self {
+            Self::And(mut filters) => {
+                filters.push(other.into());
+                Self::And(filters)
+            }
+            filter => Self::And(vec![filter, other.into()]),
+        }
+    }
+
+    /// Returns a filter matching files matching either `self` or `other`.
+    pub fn or(self, other: impl Into<Self>) -> Self {
+        match self {
+            Self::Or(mut filters) => {
+                filters.push(other.into());
+                Self::Or(filters)
+            }
+            filter => Self::Or(vec![filter, other.into()]),
+        }
+    }
+
+    /// Sets the root relative glob patterns are anchored to, see [`GlobFileFilter::with_root`].
+    #[must_use]
+    pub fn with_root(self, root: &Path) -> Self {
+        match self {
+            Self::Glob(filter) => Self::Glob(filter.with_root(root)),
+            Self::And(filters) => {
+                Self::And(filters.into_iter().map(|filter| filter.with_root(root)).collect())
+            }
+            Self::Or(filters) => {
+                Self::Or(filters.into_iter().map(|filter| filter.with_root(root)).collect())
+            }
+            Self::Not(filter) => Self::Not(Box::new(filter.with_root(root))),
+            filter => filter,
+        }
+    }
+}
+
+impl From<GlobFileFilter> for SourceFilter {
+    fn from(filter: GlobFileFilter) -> Self {
+        Self::Glob(filter)
+    }
+}
+
+impl From<ContractNameFilter> for SourceFilter {
+    fn from(filter: ContractNameFilter) -> Self {
+        Self::ContractName(filter)
+    }
+}
+
+impl ops::Not for SourceFilter {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        match self {
+            Self::Not(filter) => *filter,
+            filter => Self::Not(Box::new(filter)),
+        }
+    }
+}
+
+impl FileFilter for SourceFilter {
+    fn is_match(&self, file: &Path) -> bool {
+        match self {
+            Self::Glob(filter) => filter.is_match(file),
+            Self::PathRegex(regex) => regex.is_match(&file.to_string_lossy()),
+            Self::ContractName(filter) => filter.is_match(file),
+            Self::And(filters) => filters.iter().all(|filter| filter.is_match(file)),
+            Self::Or(filters) => filters.iter().any(|filter| filter.is_match(file)),
+            Self::Not(filter) => !filter.is_match(file),
+        }
+    }
+}
+
 pub trait MaybeSolData {
     fn sol_data(&self) -> Option<&SolData>;
 }
@@ -178,3 +421,102 @@ impl fmt::Debug for SparseOutputFilter<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_match_globs() {
+        let filter =
+            GlobFileFilter::new(["src/**/*.sol"], ["**/*.t.sol", "/root/src/Ignored.sol"]).unwrap();
+        // relative patterns match relative paths without a root
+        assert!(filter.is_match(Path::new("src/A.sol")));
+        assert!(!filter.is_match(Path::new("/root/src/A.sol")));
+
+        let filter = filter.with_root("/root");
+        assert!(filter.is_match(Path::new("/root/src/A.sol")));
+        assert!(filter.is_match(Path::new("/root/src/nested/A.sol")));
+        assert!(!filter.is_match(Path::new("/root/src/A.t.sol")));
+        assert!(!filter.is_match(Path::new("/root/src/Ignored.sol")));
+        assert!(!filter.is_match(Path::new("/root/test/A.sol")));
+        assert!(!filter.is_match(Path::new("/root/src/A.vy")));
+        // relative patterns are anchored to the root
+        assert!(!filter.is_match(Path::new("/root/lib/dep/src/A.sol")));
+        assert!(!filter.is_match(Path::new("/other/src/A.sol")));
+
+        // `*` does not match path separators
+        let filter = GlobFileFilter::new(["src/*.sol"], None::<&str>).unwrap().with_root("/root");
+        assert!(filter.is_match(Path::new("/root/src/A.sol")));
+        assert!(!filter.is_match(Path::new("/root/src/nested/A.sol")));
+
+        assert!(GlobFileFilter::default().is_match(Path::new("/root/src/A.sol")));
+        assert!(GlobFileFilter::new(["src/[.sol"], None::<&str>).is_err());
+    }
+
+    #[test]
+    fn can_combine_filters() {
+        let tmp = tempfile::tempdir().unwrap();
+        let src = tmp.path().join("src");
+        fs::create_dir_all(&src).unwrap();
+        let counter = src.join("Counter.sol");
+        fs::write(&counter, "contract Counter {}\n").unwrap();
+        let mock = src.join("Mocks.sol");
+        fs::write(&mock, "contract MockCounter {}\ncontract MockToken {}\n").unwrap();
+        let vyper = src.join("Counter.vy");
+        fs::write(&vyper, "count: public(uint256)\n").unwrap();
+
+        let filter: SourceFilter = serde_json::from_str(
+            r#"{ "and": [{ "glob": { "include": ["src/**"] } }, { "not": { "contract_name": "^Mock" } }] }"#,
+        )
+        .unwrap();
+        let filter = filter.with_root(tmp.path());
+        assert!(filter.is_match(&counter));
+        assert!(!filter.is_match(&mock));
+        assert!(filter.is_match(&vyper));
+        assert!(!filter.is_match(&tmp.path().join("test/Counter.t.sol")));
+
+        let filter = SourceFilter::PathRegex(Regex::new(r"\.vy$").unwrap())
+            .or(ContractNameFilter::new(Regex::new("Token").unwrap()));
+        assert!(!filter.is_match(&counter));
+        assert!(filter.is_match(&mock));
+        assert!(filter.is_match(&vyper));
+        assert!(!(!filter.clone()).is_match(&vyper));
+        assert!(matches!(!!filter, SourceFilter::Or(filters) if filters.len() == 2));
+
+        let filter = SourceFilter::from(GlobFileFilter::new(["src/*.sol"], None::<&str>).unwrap())
+            .and(ContractNameFilter::new(Regex::new("Counter").unwrap()))
+            .with_root(tmp.path());
+        assert!(filter.is_match(&counter));
+        assert!(filter.is_match(&mock));
+        assert!(!filter.is_match(&vyper));
+
+        let json = serde_json::to_string(&filter).unwrap();
+        assert_eq!(
+            json,
+            r#"{"and":[{"glob":{"include":["src/*.sol"],"exclude":[]}},{"contract_name":"Counter"}]}"#
+        );
+        assert!(serde_json::from_str::<SourceFilter>(r#"{"path_regex":"("}"#).is_err());
+    }
+
+    #[test]
+    fn caches_contract_names() {
+        let tmp = tempfile::tempdir().unwrap();
+        let file = tmp.path().join("Counter.sol");
+        fs::write(&file, "contract Counter {}\n").unwrap();
+        let modified = fs::metadata(&file).unwrap().modified().unwrap();
+        let filter = ContractNameFilter::new(Regex::new("^Counter$").unwrap());
+        assert!(filter.is_match(&file));
+
+        // unmodified files aren't parsed again, also not by clones
+        fs::write(&file, "contract Token {}\n").unwrap();
+        fs::File::options().write(true).open(&file).unwrap().set_modified(modified).unwrap();
+        let clone = filter.clone();
+        assert!(clone.is_match(&file));
+
+        let later = modified + std::time::Duration::from_secs(1);
+        fs::File::options().write(true).open(&file).unwrap().set_modified(later).unwrap();
+        assert!(!filter.is_match(&file));
+        assert!(!filter.is_match(&tmp.path().join("Missing.sol")));
+    }
+}
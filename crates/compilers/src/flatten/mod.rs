@@ -1,5 +1,4 @@
 use crate::{
-    apply_updates,
     compilers::{Compiler, ParsedSource},
     filter::MaybeSolData,
     resolver::parse::SolData,
@@ -10,7 +9,7 @@ use foundry_compilers_artifacts::{
     output_selection::OutputSelection,
     solc::ExternalInlineAssemblyReference,
     sources::{Source, Sources},
-    ContractDefinitionPart, SourceUnit, SourceUnitPart,
+    ContractDefinitionPart, SolcLanguage, SourceUnit, SourceUnitPart,
 };
 use foundry_compilers_core::{
     error::{Result, SolcError},
@@ -21,10 +20,17 @@ use std::{
     collections::{BTreeSet, HashMap, HashSet},
     hash::Hash,
     path::{Path, PathBuf},
-    sync::Arc,
 };
 use visitor::Walk;
 
+mod solar;
+use solar::SolarSourceUnit;
+
+mod source_map;
+mod vyper;
+pub use source_map::{FlattenedSegment, FlattenedSourceMap};
+pub use vyper::VyperFlattened;
+
 /// Alternative of `SourceLocation` which includes path of the file.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 struct ItemLocation {
@@ -115,6 +121,8 @@ impl Visitor for ReferencesCollector {
 pub struct FlatteningResult {
     /// Updated source in the order they should be written to the output file.
     sources: Vec<String>,
+    /// Segments of each updated source, mapping it to the original source.
+    segments: Vec<Vec<FlattenedSegment>>,
     /// Pragmas that should be present in the target file.
     pragmas: Vec<String>,
     /// License identifier that should be present in the target file.
@@ -123,25 +131,26 @@ pub struct FlatteningResult {
 
 impl FlatteningResult {
     fn new(
-        mut flattener: Flattener,
+        flattener: Flattener,
         updates: Updates,
         pragmas: Vec<String>,
         license: Option<String>,
     ) -> Self {
-        apply_updates(&mut flattener.sources, updates);
-
-        let sources = flattener
+        let (sources, segments) = flattener
             .ordered_sources
             .iter()
-            .map(|path| flattener.sources.remove(path).unwrap().content)
-            .map(Arc::unwrap_or_clone)
-            .collect();
+            .map(|path| {
+                let content = &flattener.sources[path].content;
+                source_map::apply_updates(path, content, updates.get(path).into_iter().flatten())
+            })
+            .unzip();
 
-        Self { sources, pragmas, license }
+        Self { sources, segments, pragmas, license }
     }
 
-    fn get_flattened_target(&self) -> String {
+    fn get_flattened_target(&self) -> (String, FlattenedSourceMap) {
         let mut result = String::new();
+        let mut source_map = FlattenedSourceMap::default();
 
         if let Some(license) = &self.license {
             result.push_str(&format!("// {license}\n"));
@@ -149,11 +158,23 @@ impl FlatteningResult {
         for pragma in &self.pragmas {
             result.push_str(&format!("{pragma}\n"));
         }
-        for source in &self.sources {
-            result.push_str(&format!("\n\n{source}"));
+        for (source, segments) in self.sources.iter().zip(&self.segments) {
+            result.push_str("\n\n");
+            source_map.extend(segments, result.len());
+            result.push_str(source);
         }
 
-        format!("{}\n", utils::RE_THREE_OR_MORE_NEWLINES.replace_all(&result, "\n\n").trim())
+        let newlines = utils::RE_THREE_OR_MORE_NEWLINES
+            .find_iter(&result)
+            .map(|m| m.start() + 2..m.end())
+            .collect::<Vec<_>>();
+        let result = source_map.remove_ranges(&result, &newlines);
+
+        let start = result.len() - result.trim_start().len();
+        let end = start + result[start..].trim_end().len();
+        let result = source_map.remove_ranges(&result, &[0..start, end..result.len()]);
+
+        (format!("{result}\n"), source_map)
     }
 }
 
@@ -171,14 +192,23 @@ impl<T: Into<SolcError>> From<T> for FlattenerError {
     }
 }
 
-/// Context for flattening. Stores all sources and ASTs that are in scope of the flattening target.
+/// ASTs of the sources in scope of the flattening target.
+enum FlattenerAsts {
+    /// Vec of (path, ast) pairs, with ASTs emitted by the compiler.
+    Solc(Vec<(PathBuf, SourceUnit)>),
+    /// Vec of (path, unit) pairs, with units collected from the ASTs of the `solar` parser, in the
+    /// order of `ordered_sources`.
+    Solar(Vec<(PathBuf, SolarSourceUnit)>),
+}
+
+/// Context for flattening. Stores all sources and ASTs that are in scope of the flattening targets.
 pub struct Flattener {
-    /// Target file to flatten.
-    target: PathBuf,
-    /// Sources including only target and it dependencies (imports of any depth).
+    /// Target files to flatten.
+    targets: Vec<PathBuf>,
+    /// Sources including only targets and their dependencies (imports of any depth).
     sources: Sources,
-    /// Vec of (path, ast) pairs.
-    asts: Vec<(PathBuf, SourceUnit)>,
+    /// ASTs of the sources.
+    asts: FlattenerAsts,
     /// Sources in the order they should be written to the output file.
     ordered_sources: Vec<PathBuf>,
     /// Project root directory.
@@ -188,20 +218,37 @@ pub struct Flattener {
 impl Flattener {
     /// Compiles the target file and prepares AST and analysis data for flattening.
     pub fn new<C: Compiler, T: ArtifactOutput<CompilerContract = C::CompilerContract>>(
-        mut project: Project<C, T>,
+        project: Project<C, T>,
         target: &Path,
     ) -> std::result::Result<Self, FlattenerError>
     where
         C::ParsedSource: MaybeSolData,
     {
-        // Configure project to compile the target file and only request AST for target file.
+        Self::new_many(project, &[target.to_path_buf()])
+    }
+
+    /// Compiles the target files and prepares AST and analysis data for flattening them into a
+    /// single file.
+    ///
+    /// Dependencies shared by the targets are only included once, and name conflicts are resolved
+    /// across all of them. Targets which are not imported by other targets are placed last, in the
+    /// given order.
+    pub fn new_many<C: Compiler, T: ArtifactOutput<CompilerContract = C::CompilerContract>>(
+        mut project: Project<C, T>,
+        targets: &[PathBuf],
+    ) -> std::result::Result<Self, FlattenerError>
+    where
+        C::ParsedSource: MaybeSolData,
+    {
+        // Configure project to compile the target files and only request AST for target files.
         project.cached = false;
         project.no_artifacts = true;
         project.settings.update_output_selection(|selection| {
             *selection = OutputSelection::ast_output_selection();
         });
 
-        let output = project.compile_file(target).map_err(FlattenerError::Compilation)?;
+        let output =
+            project.compile_files(targets.iter().cloned()).map_err(FlattenerError::Compilation)?;
 
         if output.has_compiler_errors() {
             return Err(FlattenerError::Compilation(SolcError::msg(&output)));
@@ -209,10 +256,10 @@ impl Flattener {
 
         let output = output.compiler_output;
 
-        let sources = Source::read_all_files(vec![target.to_path_buf()])?;
+        let sources = Source::read_all_files(targets.to_vec())?;
         let graph = Graph::<C::ParsedSource>::resolve_sources(&project.paths, sources)?;
 
-        let ordered_sources = collect_ordered_deps(target, &project.paths, &graph)?;
+        let ordered_sources = collect_ordered_deps_many(targets, &project.paths, &graph)?;
 
         #[cfg(windows)]
         let ordered_sources = {
@@ -240,15 +287,69 @@ impl Flattener {
         }
 
         Ok(Self {
-            target: target.into(),
+            targets: targets.to_vec(),
             sources,
-            asts,
+            asts: FlattenerAsts::Solc(asts),
             ordered_sources,
             project_root: project.root().to_path_buf(),
         })
     }
 
-    /// Flattens target file and returns the result as a string
+    /// Parses the target file and its dependencies and prepares analysis data for flattening,
+    /// without invoking the compiler.
+    ///
+    /// References are resolved by name via imports rather than via compiler ASTs, so there is no
+    /// need for a matching `solc`. However, calls to free functions attached to types via
+    /// `using {..} for` can't be resolved, so such functions keep their names even if they collide.
+    pub fn new_offline<L: Clone>(paths: &ProjectPathsConfig<L>, target: &Path) -> Result<Self> {
+        Self::new_offline_many(paths, &[target.to_path_buf()])
+    }
+
+    /// Parses the target files and their dependencies and prepares analysis data for flattening
+    /// them into a single file, without invoking the compiler.
+    ///
+    /// See [`Flattener::new_many`] and [`Flattener::new_offline`].
+    pub fn new_offline_many<L: Clone>(
+        paths: &ProjectPathsConfig<L>,
+        targets: &[PathBuf],
+    ) -> Result<Self> {
+        let paths = paths.clone().with_language::<SolcLanguage>();
+
+        let sources = Source::read_all_files(targets.to_vec())?;
+        let graph = Graph::<SolData>::resolve_sources(&paths, sources)?;
+
+        let ordered_sources = collect_ordered_deps_many(targets, &paths, &graph)?;
+
+        #[cfg(windows)]
+        let ordered_sources = {
+            let mut sources = ordered_sources;
+            use path_slash::PathBufExt;
+            for p in &mut sources {
+                *p = PathBuf::from(p.to_slash_lossy().to_string());
+            }
+            sources
+        };
+
+        let sources = Source::read_all(&ordered_sources)?;
+
+        let units = ordered_sources
+            .iter()
+            .map(|path| {
+                let unit = SolarSourceUnit::parse(path, &sources[path].content, &paths)?;
+                Ok((path.clone(), unit))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            targets: targets.to_vec(),
+            sources,
+            asts: FlattenerAsts::Solar(units),
+            ordered_sources,
+            project_root: paths.root,
+        })
+    }
+
+    /// Flattens target files and returns the result as a string
     ///
     /// Flattening process includes following steps:
     /// 1. Find all file-level definitions and rename references to them via aliased or qualified
@@ -256,15 +357,26 @@ impl Flattener {
     /// 2. Find all duplicates among file-level definitions and rename them to avoid conflicts.
     /// 3. Remove all imports.
     /// 4. Remove all pragmas except for the ones in the target file.
-    /// 5. Remove all license identifiers except for the one in the target file.
+    /// 5. Remove all license identifiers except for the ones in the target files.
     pub fn flatten(self) -> String {
+        self.flatten_with_source_map().0
+    }
+
+    /// Flattens target file like [`Flattener::flatten`] and returns the result together with a
+    /// source map, which maps ranges of the result back to the original sources.
+    pub fn flatten_with_source_map(self) -> (String, FlattenedSourceMap) {
         let mut updates = Updates::new();
 
         self.append_filenames(&mut updates);
-        let top_level_names = self.rename_top_level_definitions(&mut updates);
-        self.rename_contract_level_types_references(&top_level_names, &mut updates);
-        self.remove_qualified_imports(&mut updates);
-        self.update_inheritdocs(&top_level_names, &mut updates);
+        match &self.asts {
+            FlattenerAsts::Solc(asts) => {
+                let top_level_names = self.rename_top_level_definitions(asts, &mut updates);
+                self.rename_contract_level_types_references(asts, &top_level_names, &mut updates);
+                self.remove_qualified_imports(asts, &mut updates);
+                self.update_inheritdocs(asts, &top_level_names, &mut updates);
+            }
+            FlattenerAsts::Solar(units) => solar::rename_definitions(units, &mut updates),
+        }
 
         self.remove_imports(&mut updates);
         let target_pragmas = self.process_pragmas(&mut updates);
@@ -302,9 +414,13 @@ impl Flattener {
     /// Two Counter contracts will be renamed to Counter_0 and Counter_1
     ///
     /// Returns mapping from top-level declaration id to its name (possibly updated)
-    fn rename_top_level_definitions(&self, updates: &mut Updates) -> HashMap<usize, String> {
-        let top_level_definitions = self.collect_top_level_definitions();
-        let references = self.collect_references();
+    fn rename_top_level_definitions(
+        &self,
+        asts: &[(PathBuf, SourceUnit)],
+        updates: &mut Updates,
+    ) -> HashMap<usize, String> {
+        let top_level_definitions = self.collect_top_level_definitions(asts);
+        let references = self.collect_references(asts);
 
         let mut top_level_names = HashMap::new();
 
@@ -361,9 +477,8 @@ impl Flattener {
     /// This should work correctly for vast majority of cases, however there are situations for
     /// which such approach won't work, most of which are related to code being formatted in an
     /// uncommon way.
-    fn remove_qualified_imports(&self, updates: &mut Updates) {
-        let imports_ids = self
-            .asts
+    fn remove_qualified_imports(&self, asts: &[(PathBuf, SourceUnit)], updates: &mut Updates) {
+        let imports_ids = asts
             .iter()
             .flat_map(|(_, ast)| {
                 ast.nodes.iter().filter_map(|node| match node {
@@ -373,7 +488,7 @@ impl Flattener {
             })
             .collect::<HashSet<_>>();
 
-        let references = self.collect_references();
+        let references = self.collect_references(asts);
 
         for (id, locs) in references {
             if !imports_ids.contains(&(id as usize)) {
@@ -396,15 +511,16 @@ impl Flattener {
     /// This will only operate on references from `IdentifierPath` nodes.
     fn rename_contract_level_types_references(
         &self,
+        asts: &[(PathBuf, SourceUnit)],
         top_level_names: &HashMap<usize, String>,
         updates: &mut Updates,
     ) {
-        let contract_level_definitions = self.collect_contract_level_definitions();
+        let contract_level_definitions = self.collect_contract_level_definitions(asts);
 
-        for (path, ast) in &self.asts {
+        for (path, ast) in asts {
             for node in &ast.nodes {
                 let mut collector =
-                    ReferencesCollector { path: self.target.clone(), references: HashMap::new() };
+                    ReferencesCollector { path: path.clone(), references: HashMap::new() };
 
                 node.walk(&mut collector);
 
@@ -441,9 +557,14 @@ impl Flattener {
     ///
     /// We will either replace contract name or remove @inheritdoc tag completely to avoid
     /// generating invalid source code.
-    fn update_inheritdocs(&self, top_level_names: &HashMap<usize, String>, updates: &mut Updates) {
+    fn update_inheritdocs(
+        &self,
+        asts: &[(PathBuf, SourceUnit)],
+        top_level_names: &HashMap<usize, String>,
+        updates: &mut Updates,
+    ) {
         trace!("updating @inheritdoc tags");
-        for (path, ast) in &self.asts {
+        for (path, ast) in asts {
             // Collect all exported symbols for this source unit
             // @inheritdoc value is either one of those or qualified import path which we don't
             // support
@@ -546,9 +667,11 @@ impl Flattener {
 
     /// Processes all ASTs and collects all top-level definitions in the form of
     /// a mapping from name to (definition id, source location)
-    fn collect_top_level_definitions(&self) -> HashMap<&String, HashSet<(usize, ItemLocation)>> {
-        self.asts
-            .iter()
+    fn collect_top_level_definitions<'a>(
+        &self,
+        asts: &'a [(PathBuf, SourceUnit)],
+    ) -> HashMap<&'a String, HashSet<(usize, ItemLocation)>> {
+        asts.iter()
             .flat_map(|(path, ast)| {
                 ast.nodes
                     .iter()
@@ -609,9 +732,11 @@ impl Flattener {
 
     /// Collect all contract-level definitions in the form of a mapping from definition id to
     /// (definition name, contract id)
-    fn collect_contract_level_definitions(&self) -> HashMap<usize, (&String, usize)> {
-        self.asts
-            .iter()
+    fn collect_contract_level_definitions<'a>(
+        &self,
+        asts: &'a [(PathBuf, SourceUnit)],
+    ) -> HashMap<usize, (&'a String, usize)> {
+        asts.iter()
             .flat_map(|(_, ast)| {
                 ast.nodes.iter().filter_map(|node| match node {
                     SourceUnitPart::ContractDefinition(contract) => {
@@ -651,9 +776,11 @@ impl Flattener {
 
     /// Collects all references to any declaration in the form of a mapping from declaration id to
     /// set of source locations it appears in
-    fn collect_references(&self) -> HashMap<isize, HashSet<ItemLocation>> {
-        self.asts
-            .iter()
+    fn collect_references(
+        &self,
+        asts: &[(PathBuf, SourceUnit)],
+    ) -> HashMap<isize, HashSet<ItemLocation>> {
+        asts.iter()
             .flat_map(|(path, ast)| {
                 let mut collector =
                     ReferencesCollector { path: path.clone(), references: HashMap::new() };
@@ -679,17 +806,29 @@ impl Flattener {
 
     // Collects all imports locations.
     fn collect_imports(&self) -> HashSet<ItemLocation> {
-        self.asts
-            .iter()
-            .flat_map(|(path, ast)| {
-                ast.nodes.iter().filter_map(|node| match node {
-                    SourceUnitPart::ImportDirective(import) => {
-                        ItemLocation::try_from_source_loc(&import.src, path.clone())
-                    }
-                    _ => None,
+        match &self.asts {
+            FlattenerAsts::Solc(asts) => asts
+                .iter()
+                .flat_map(|(path, ast)| {
+                    ast.nodes.iter().filter_map(|node| match node {
+                        SourceUnitPart::ImportDirective(import) => {
+                            ItemLocation::try_from_source_loc(&import.src, path.clone())
+                        }
+                        _ => None,
+                    })
                 })
-            })
-            .collect()
+                .collect(),
+            FlattenerAsts::Solar(units) => units
+                .iter()
+                .flat_map(|(path, unit)| {
+                    unit.imports.iter().map(|import| ItemLocation {
+                        path: path.clone(),
+                        start: import.loc.start,
+                        end: import.loc.end,
+                    })
+                })
+                .collect(),
+        }
     }
 
     /// Removes all pragma directives from all sources. Returns Vec with experimental and combined
@@ -732,29 +871,44 @@ impl Flattener {
 
     // Collects all pragma directives locations.
     fn collect_pragmas(&self) -> HashSet<ItemLocation> {
-        self.asts
-            .iter()
-            .flat_map(|(path, ast)| {
-                ast.nodes.iter().filter_map(|node| match node {
-                    SourceUnitPart::PragmaDirective(import) => {
-                        ItemLocation::try_from_source_loc(&import.src, path.clone())
-                    }
-                    _ => None,
+        match &self.asts {
+            FlattenerAsts::Solc(asts) => asts
+                .iter()
+                .flat_map(|(path, ast)| {
+                    ast.nodes.iter().filter_map(|node| match node {
+                        SourceUnitPart::PragmaDirective(import) => {
+                            ItemLocation::try_from_source_loc(&import.src, path.clone())
+                        }
+                        _ => None,
+                    })
                 })
-            })
-            .collect()
+                .collect(),
+            FlattenerAsts::Solar(units) => units
+                .iter()
+                .flat_map(|(path, unit)| {
+                    unit.pragmas.iter().map(|loc| ItemLocation {
+                        path: path.clone(),
+                        start: loc.start,
+                        end: loc.end,
+                    })
+                })
+                .collect(),
+        }
     }
 
     /// Removes all license identifiers from all sources. Returns license identifier from target
-    /// file, if any.
+    /// files, if any.
+    ///
+    /// Different licenses of multiple targets are combined into a single `AND` expression.
     fn process_licenses(&self, updates: &mut Updates) -> Option<String> {
-        let mut target_license = None;
+        let mut target_licenses = HashMap::new();
 
         for loc in &self.collect_licenses() {
-            if loc.path == self.target {
+            if self.targets.contains(&loc.path) {
                 let license_line = self.read_location(loc);
                 let license_start = license_line.find("SPDX-License-Identifier:").unwrap();
-                target_license = Some(license_line[license_start..].trim().to_string());
+                target_licenses
+                    .insert(loc.path.clone(), license_line[license_start..].trim().to_string());
             }
             updates.entry(loc.path.clone()).or_default().insert((
                 loc.start,
@@ -763,7 +917,25 @@ impl Flattener {
             ));
         }
 
-        target_license
+        let mut licenses = Vec::new();
+        for license in self.targets.iter().filter_map(|target| target_licenses.remove(target)) {
+            if !licenses.contains(&license) {
+                licenses.push(license);
+            }
+        }
+        if licenses.len() <= 1 {
+            return licenses.pop();
+        }
+
+        let expressions = licenses.iter().map(|license| {
+            let expression = license["SPDX-License-Identifier:".len()..].trim();
+            if expression.contains(char::is_whitespace) {
+                format!("({expression})")
+            } else {
+                expression.to_string()
+            }
+        });
+        Some(format!("SPDX-License-Identifier: {}", expressions.format(" AND ")))
     }
 
     // Collects all SPDX-License-Identifier locations.
@@ -834,13 +1006,36 @@ pub fn collect_ordered_deps<D: ParsedSource + MaybeSolData>(
     path: &Path,
     paths: &ProjectPathsConfig<D::Language>,
     graph: &Graph<D>,
+) -> Result<Vec<PathBuf>> {
+    collect_ordered_deps_many(&[path.to_path_buf()], paths, graph)
+}
+
+/// Collects the dependencies of multiple targets in the same order as [`collect_ordered_deps`],
+/// with each shared dependency included once.
+///
+/// Targets which are imported by other targets are ordered like any other dependency, the
+/// remaining ones are placed last, in the given order.
+pub fn collect_ordered_deps_many<D: ParsedSource + MaybeSolData>(
+    targets: &[PathBuf],
+    paths: &ProjectPathsConfig<D::Language>,
+    graph: &Graph<D>,
 ) -> Result<Vec<PathBuf>> {
     let mut deps = HashSet::new();
-    collect_deps(path, paths, graph, &mut deps)?;
+    for target in targets {
+        let mut target_deps = HashSet::new();
+        collect_deps(target, paths, graph, &mut target_deps)?;
+        target_deps.remove(target);
+        deps.extend(target_deps);
+    }
 
-    // Remove path prior counting dependencies
-    // It will be added later to the end of resulted Vec
-    deps.remove(path);
+    // Remove roots prior counting dependencies
+    // They will be added later to the end of resulted Vec
+    let mut roots = Vec::new();
+    for target in targets {
+        if !deps.contains(target) && !roots.contains(target) {
+            roots.push(target.clone());
+        }
+    }
 
     let mut paths_with_deps_count = Vec::new();
     for path in deps {
@@ -871,7 +1066,7 @@ pub fn collect_ordered_deps<D: ParsedSource + MaybeSolData>(
     let mut ordered_deps =
         paths_with_deps_count.into_iter().map(|(_, path)| path).collect::<Vec<_>>();
 
-    ordered_deps.push(path.to_path_buf());
+    ordered_deps.extend(roots);
 
     Ok(ordered_deps)
 }
@@ -890,3 +1085,118 @@ pub fn combine_version_pragmas(pragmas: &[impl AsRef<str>]) -> Option<String> {
     }
     Some(format!("pragma solidity {};", versions.iter().format(" ")))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn can_flatten_multiple_targets() {
+        let tmp = tempfile::tempdir().unwrap();
+        let paths: ProjectPathsConfig<SolcLanguage> =
+            ProjectPathsConfig::dapptools(tmp.path()).unwrap();
+        fs::create_dir_all(&paths.sources).unwrap();
+        let write = |name: &str, content: &str| {
+            let path = paths.sources.join(name);
+            fs::write(&path, content).unwrap();
+            path
+        };
+        write(
+            "Ownable.sol",
+            r"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.10;
+
+contract Ownable {}
+",
+        );
+        write(
+            "Helper.sol",
+            r"pragma solidity ^0.8.10;
+
+contract Helper {}
+",
+        );
+        let child = write(
+            "Child.sol",
+            r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.10;
+import {Ownable} from "./Ownable.sol";
+
+contract Child is Ownable {}
+"#,
+        );
+        let factory = write(
+            "Factory.sol",
+            r#"// SPDX-License-Identifier: GPL-3.0-or-later OR Apache-2.0
+pragma solidity ^0.8.12;
+import "./Ownable.sol";
+import {Helper as Base} from "./Helper.sol";
+import {Child} from "./Child.sol";
+
+contract Helper is Base {}
+
+contract Factory is Ownable {
+    function deploy() external returns (Child) {
+        return new Child();
+    }
+}
+"#,
+        );
+        let proxy = write(
+            "Proxy.sol",
+            r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.10;
+import "./Helper.sol";
+
+contract Proxy is Helper {}
+"#,
+        );
+
+        let ordered = collect_ordered_deps_many(
+            &[child.clone(), factory.clone(), proxy.clone()],
+            &paths,
+            &Graph::<SolData>::resolve(&paths).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            ordered,
+            ["Helper.sol", "Ownable.sol", "Child.sol", "Factory.sol", "Proxy.sol"]
+                .map(|name| paths.sources.join(name))
+        );
+
+        let result = paths.flatten_many(&[factory, proxy, child]).unwrap();
+        assert_eq!(
+            result,
+            r"// SPDX-License-Identifier: (GPL-3.0-or-later OR Apache-2.0) AND MIT
+pragma solidity ^0.8.10 ^0.8.12;
+
+// src/Helper.sol
+
+contract Helper_0 {}
+
+// src/Ownable.sol
+
+contract Ownable {}
+
+// src/Child.sol
+
+contract Child is Ownable {}
+
+// src/Factory.sol
+
+contract Helper_1 is Helper_0 {}
+
+contract Factory is Ownable {
+    function deploy() external returns (Child) {
+        return new Child();
+    }
+}
+
+// src/Proxy.sol
+
+contract Proxy is Helper_0 {}
+"
+        );
+    }
+}
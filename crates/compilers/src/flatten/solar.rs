@@ -0,0 +1,853 @@
+//! Offline analysis for the [`Flattener`](super::Flattener), based on the AST of the `solar`
+//! parser.
+//!
+//! Without compiler output there are no declaration ids to follow, so references are resolved by
+//! name instead: every source gets a file-level scope built from its definitions and imports, and
+//! identifiers and paths are looked up in the scope of the source they appear in.
+
+use crate::{parse_one_source, ProjectPathsConfig, Updates};
+use foundry_compilers_artifacts::SolcLanguage;
+use foundry_compilers_core::error::{Result, SolcError};
+use solar_parse::{
+    ast::{self, visit::Visit},
+    interface::{data_structures::Never, Ident},
+};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    ops::{ControlFlow, Range},
+    path::{Path, PathBuf},
+};
+
+/// A path referencing a declaration, e.g. `A.B.C`, as name and location of every segment.
+type Reference = Vec<(String, Range<usize>)>;
+
+/// File-level items of a source and all references in it, collected from its AST.
+#[derive(Debug, Default)]
+pub(super) struct SolarSourceUnit {
+    /// Import directives.
+    pub(super) imports: Vec<Import>,
+    /// Locations of pragma directives.
+    pub(super) pragmas: Vec<Range<usize>>,
+    /// File-level definitions.
+    definitions: Vec<Definition>,
+    /// References to declarations via identifiers and paths.
+    references: Vec<Reference>,
+    /// References to free functions attached to types via `using {..} for`.
+    attached_functions: Vec<Reference>,
+    /// `@inheritdoc` tags in natspec comments.
+    inheritdocs: Vec<InheritDoc>,
+}
+
+#[derive(Debug)]
+pub(super) struct Import {
+    /// Location of the whole directive.
+    pub(super) loc: Range<usize>,
+    /// Resolved path of the imported source.
+    path: PathBuf,
+    items: ImportItems,
+}
+
+#[derive(Debug)]
+enum ImportItems {
+    /// `import "A.sol";`, importing all symbols of the source.
+    Plain,
+    /// `import "A.sol" as A;` or `import * as A from "A.sol";`.
+    Module(String),
+    /// `import {A, B as C} from "A.sol";`, as (name, local name) pairs.
+    Symbols(Vec<(String, String)>),
+}
+
+#[derive(Debug)]
+struct Definition {
+    name: String,
+    /// Location of the name.
+    loc: Range<usize>,
+    /// Whether the definition is renamed if the name is defined in multiple sources.
+    ///
+    /// Events and errors are never renamed, same as with compiler ASTs.
+    renameable: bool,
+}
+
+#[derive(Debug)]
+struct InheritDoc {
+    /// The referenced contract, split into path segments.
+    reference: Reference,
+    /// Location of the tag, including the name.
+    loc: Range<usize>,
+}
+
+impl SolarSourceUnit {
+    /// Parses the source at `path` and resolves its imports.
+    pub(super) fn parse(
+        path: &Path,
+        content: &str,
+        paths: &ProjectPathsConfig<SolcLanguage>,
+    ) -> Result<Self> {
+        let mut imports = Vec::new();
+        let mut unit = parse_one_source(content, path, |ast| {
+            let mut unit = Self::default();
+            for item in ast.items.iter() {
+                let loc = item.span.to_range();
+                match &item.kind {
+                    ast::ItemKind::Pragma(_) => unit.pragmas.push(loc),
+                    ast::ItemKind::Import(import) => {
+                        let items = match &import.items {
+                            ast::ImportItems::Plain(None) => ImportItems::Plain,
+                            ast::ImportItems::Plain(Some(alias))
+                            | ast::ImportItems::Glob(alias) => {
+                                ImportItems::Module(alias.to_string())
+                            }
+                            ast::ImportItems::Aliases(aliases) => ImportItems::Symbols(
+                                aliases
+                                    .iter()
+                                    .map(|(name, alias)| {
+                                        (name.to_string(), alias.unwrap_or(*name).to_string())
+                                    })
+                                    .collect(),
+                            ),
+                        };
+                        imports.push((loc, import.path.value.to_string(), items));
+                    }
+                    kind => {
+                        if let Some(name) = kind.name() {
+                            unit.definitions.push(Definition {
+                                name: name.to_string(),
+                                loc: name.span.to_range(),
+                                renameable: !matches!(
+                                    kind,
+                                    ast::ItemKind::Error(_) | ast::ItemKind::Event(_)
+                                ),
+                            });
+                        }
+                    }
+                }
+            }
+
+            let mut collector = ReferencesCollector { content, ..Default::default() };
+            let _ = collector.visit_source_unit(&ast);
+            unit.references = collector.references;
+            unit.attached_functions = collector.attached_functions;
+            unit.inheritdocs = collector.inheritdocs;
+            unit
+        })
+        .map_err(|err| SolcError::ParseError(err.to_string()))?;
+
+        let dir = path.parent().ok_or_else(|| {
+            SolcError::msg(format!("failed to get parent directory for \"{}\"", path.display()))
+        })?;
+        for (loc, import, items) in imports {
+            let path = paths.resolve_import(dir, Path::new(&import))?;
+            #[cfg(windows)]
+            let path = {
+                use path_slash::PathBufExt;
+                PathBuf::from(path.to_slash_lossy().to_string())
+            };
+            unit.imports.push(Import { loc, path, items });
+        }
+
+        Ok(unit)
+    }
+}
+
+/// Visitor collecting all identifiers and paths which might reference file-level definitions.
+///
+/// Member accesses on an identifier (`a.b.c`) are collected as a single path, so that qualified
+/// imports can be resolved. Names declared in the enclosing contract or function shadow file-level
+/// definitions, thus references to them are skipped.
+#[derive(Default)]
+struct ReferencesCollector<'a> {
+    content: &'a str,
+    references: Vec<Reference>,
+    attached_functions: Vec<Reference>,
+    inheritdocs: Vec<InheritDoc>,
+    /// Names declared in the enclosing contract and function.
+    shadowed: Vec<HashSet<String>>,
+}
+
+impl ReferencesCollector<'_> {
+    fn collect<'i>(&mut self, segments: impl IntoIterator<Item = &'i Ident>) {
+        let reference = segments
+            .into_iter()
+            .map(|ident| (ident.to_string(), ident.span.to_range()))
+            .collect::<Reference>();
+        let Some((name, _)) = reference.first() else { return };
+        if !self.shadowed.iter().any(|names| names.contains(name)) {
+            self.references.push(reference);
+        }
+    }
+}
+
+fn to_reference(path: &ast::PathSlice) -> Reference {
+    path.segments().iter().map(|ident| (ident.to_string(), ident.span.to_range())).collect()
+}
+
+impl<'ast> Visit<'ast> for ReferencesCollector<'_> {
+    type BreakValue = Never;
+
+    fn visit_item_contract(
+        &mut self,
+        contract: &'ast ast::ItemContract<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        for base in contract.bases.iter() {
+            self.visit_modifier(base)?;
+        }
+        self.shadowed.push(
+            contract.body.iter().filter_map(|item| item.name()).map(|n| n.to_string()).collect(),
+        );
+        for item in contract.body.iter() {
+            self.visit_item(item)?;
+        }
+        self.shadowed.pop();
+        ControlFlow::Continue(())
+    }
+
+    fn visit_item_function(
+        &mut self,
+        function: &'ast ast::ItemFunction<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        let mut locals = LocalsCollector::default();
+        let _ = locals.visit_item_function(function);
+        self.shadowed.push(locals.names);
+        self.walk_item_function(function)?;
+        self.shadowed.pop();
+        ControlFlow::Continue(())
+    }
+
+    fn visit_function_header(
+        &mut self,
+        header: &'ast ast::FunctionHeader<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        self.walk_function_header(header)?;
+        if let Some(override_) = &header.override_ {
+            for path in override_.paths.iter() {
+                self.visit_path(path)?;
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_variable_definition(
+        &mut self,
+        var: &'ast ast::VariableDefinition<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        self.walk_variable_definition(var)?;
+        if let Some(override_) = &var.override_ {
+            for path in override_.paths.iter() {
+                self.visit_path(path)?;
+            }
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_using_directive(
+        &mut self,
+        using: &'ast ast::UsingDirective<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        if let ast::UsingList::Multiple(paths) = &using.list {
+            self.attached_functions.extend(paths.iter().map(|(path, _)| to_reference(path)));
+        }
+        self.walk_using_directive(using)
+    }
+
+    fn visit_expr(&mut self, expr: &'ast ast::Expr<'ast>) -> ControlFlow<Self::BreakValue> {
+        let mut members = Vec::new();
+        let mut base = expr;
+        while let ast::ExprKind::Member(inner, member) = &base.kind {
+            members.push(member);
+            base = inner;
+        }
+        if let ast::ExprKind::Ident(ident) = &base.kind {
+            self.collect(std::iter::once(ident).chain(members.into_iter().rev()));
+            return ControlFlow::Continue(());
+        }
+        self.walk_expr(expr)
+    }
+
+    fn visit_path(&mut self, path: &'ast ast::PathSlice) -> ControlFlow<Self::BreakValue> {
+        self.collect(path.segments());
+        ControlFlow::Continue(())
+    }
+
+    fn visit_doc_comment(&mut self, doc: &'ast ast::DocComment) -> ControlFlow<Self::BreakValue> {
+        let span = doc.span.to_range();
+        let content = &self.content[span.clone()];
+        let tag_len = "@inheritdoc".len();
+
+        // Same parsing as for compiler ASTs, see `Flattener::update_inheritdocs`.
+        if let Some(tag_start) = content.find("@inheritdoc") {
+            if let Some(name_start) =
+                content[tag_start + tag_len..].find(|c| c != ' ').map(|p| p + tag_start + tag_len)
+            {
+                let name_end = content[name_start..]
+                    .find([' ', '\n', '*', '/'])
+                    .map(|p| p + name_start)
+                    .unwrap_or(content.len());
+
+                let mut offset = span.start + name_start;
+                let reference = content[name_start..name_end]
+                    .split('.')
+                    .map(|segment| {
+                        let loc = offset..offset + segment.len();
+                        offset = loc.end + 1;
+                        (segment.to_string(), loc)
+                    })
+                    .collect();
+                self.inheritdocs.push(InheritDoc {
+                    reference,
+                    loc: span.start + tag_start..span.start + name_end,
+                });
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+/// Visitor collecting the names of all parameters and local variables of a function.
+#[derive(Default)]
+struct LocalsCollector {
+    names: HashSet<String>,
+}
+
+impl<'ast> Visit<'ast> for LocalsCollector {
+    type BreakValue = Never;
+
+    fn visit_variable_definition(
+        &mut self,
+        var: &'ast ast::VariableDefinition<'ast>,
+    ) -> ControlFlow<Self::BreakValue> {
+        if let Some(name) = var.name {
+            self.names.insert(name.to_string());
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+/// A symbol in the file-level scope of a source.
+#[derive(Clone, Copy, Debug)]
+enum Symbol<'a> {
+    /// A definition, as index of the defining source and name.
+    Definition(usize, &'a str),
+    /// A source imported under an alias, as index of the source.
+    Module(usize),
+}
+
+/// File-level scopes of all sources, computed on demand.
+struct Scopes<'a> {
+    units: &'a [(PathBuf, SolarSourceUnit)],
+    indices: HashMap<&'a Path, usize>,
+    scopes: Vec<Option<HashMap<&'a str, Symbol<'a>>>>,
+    /// Sources whose scope is being computed, to break import cycles.
+    in_progress: HashSet<usize>,
+}
+
+impl<'a> Scopes<'a> {
+    fn new(units: &'a [(PathBuf, SolarSourceUnit)]) -> Self {
+        Self {
+            units,
+            indices: units.iter().enumerate().map(|(i, (path, _))| (path.as_path(), i)).collect(),
+            scopes: vec![None; units.len()],
+            in_progress: HashSet::new(),
+        }
+    }
+
+    /// Returns all symbols visible at the file level of the source, which are also the symbols
+    /// exported by it.
+    fn get(&mut self, idx: usize) -> &HashMap<&'a str, Symbol<'a>> {
+        if self.scopes[idx].is_none() {
+            let scope = self.compute(idx);
+            self.scopes[idx] = Some(scope);
+        }
+        self.scopes[idx].as_ref().unwrap()
+    }
+
+    fn compute(&mut self, idx: usize) -> HashMap<&'a str, Symbol<'a>> {
+        let units = self.units;
+        let unit = &units[idx].1;
+        let definitions = unit
+            .definitions
+            .iter()
+            .map(|def| (def.name.as_str(), Symbol::Definition(idx, def.name.as_str())));
+
+        // Import cycle, only the definitions of the source are known at this point.
+        if !self.in_progress.insert(idx) {
+            return definitions.collect();
+        }
+
+        let mut scope = HashMap::new();
+        for import in &unit.imports {
+            let Some(&imported) = self.indices.get(import.path.as_path()) else { continue };
+            match &import.items {
+                ImportItems::Plain => {
+                    let symbols = self.get(imported).clone();
+                    scope.extend(symbols);
+                }
+                ImportItems::Module(alias) => {
+                    scope.insert(alias.as_str(), Symbol::Module(imported));
+                }
+                ImportItems::Symbols(symbols) => {
+                    for (name, alias) in symbols {
+                        if let Some(symbol) = self.get(imported).get(name.as_str()).copied() {
+                            scope.insert(alias.as_str(), symbol);
+                        }
+                    }
+                }
+            }
+        }
+        scope.extend(definitions);
+
+        self.in_progress.remove(&idx);
+        scope
+    }
+
+    /// Resolves a reference in the source to the definition it points to.
+    ///
+    /// Returns the index of the defining source, the name of the definition and the number of
+    /// segments consumed, which is more than 1 for qualified imports.
+    fn resolve(&mut self, idx: usize, reference: &Reference) -> Option<(usize, &'a str, usize)> {
+        let (name, _) = reference.first()?;
+        let mut symbol = *self.get(idx).get(name.as_str())?;
+        let mut consumed = 1;
+        while let Symbol::Module(module) = symbol {
+            let (name, _) = reference.get(consumed)?;
+            symbol = *self.get(module).get(name.as_str())?;
+            consumed += 1;
+        }
+        let Symbol::Definition(source, name) = symbol else { unreachable!() };
+        Some((source, name, consumed))
+    }
+}
+
+/// Finds all file-level definitions with the same name in different sources and renames them to
+/// avoid conflicts, then updates all references to file-level definitions to use their (possibly
+/// updated) names, which removes aliased and qualified imports.
+///
+/// Two `Counter` contracts will be renamed to `Counter_0` and `Counter_1`, indexed in the order
+/// of `units`.
+///
+/// Free functions attached to types via `using {..} for` are never renamed, because calls to them
+/// via member access can't be resolved without type information.
+pub(super) fn rename_definitions(units: &[(PathBuf, SolarSourceUnit)], updates: &mut Updates) {
+    let mut scopes = Scopes::new(units);
+
+    let mut attached_functions = HashSet::new();
+    for (idx, (_, unit)) in units.iter().enumerate() {
+        for reference in &unit.attached_functions {
+            if let Some((source, name, _)) = scopes.resolve(idx, reference) {
+                attached_functions.insert((source, name));
+            }
+        }
+    }
+
+    let mut definitions = BTreeMap::<&str, Vec<usize>>::new();
+    for (idx, (_, unit)) in units.iter().enumerate() {
+        for def in &unit.definitions {
+            let name = def.name.as_str();
+            if def.renameable && !attached_functions.contains(&(idx, name)) {
+                let sources = definitions.entry(name).or_default();
+                // overloaded functions are defined multiple times in the same source
+                if !sources.contains(&idx) {
+                    sources.push(idx);
+                }
+            }
+        }
+    }
+
+    let mut new_names = HashMap::new();
+    for (name, sources) in definitions {
+        if sources.len() > 1 {
+            for (i, source) in sources.into_iter().enumerate() {
+                new_names.insert((source, name), format!("{name}_{i}"));
+            }
+        }
+    }
+
+    for (idx, (path, unit)) in units.iter().enumerate() {
+        let updates = updates.entry(path.clone()).or_default();
+
+        for def in &unit.definitions {
+            if let Some(new_name) = new_names.get(&(idx, def.name.as_str())) {
+                updates.insert((def.loc.start, def.loc.end, new_name.clone()));
+            }
+        }
+
+        for reference in &unit.references {
+            let Some((source, name, consumed)) = scopes.resolve(idx, reference) else { continue };
+            let new_name = new_names.get(&(source, name)).map(String::as_str).unwrap_or(name);
+            if consumed == 1 && reference[0].0 == new_name {
+                continue;
+            }
+            updates.insert((
+                reference[0].1.start,
+                reference[consumed - 1].1.end,
+                new_name.to_string(),
+            ));
+        }
+
+        for inheritdoc in &unit.inheritdocs {
+            let reference = &inheritdoc.reference;
+            match scopes.resolve(idx, reference) {
+                Some((source, name, consumed)) if consumed == reference.len() => {
+                    let new_name =
+                        new_names.get(&(source, name)).map(String::as_str).unwrap_or(name);
+                    if consumed > 1 || reference[0].0 != new_name {
+                        updates.insert((
+                            reference[0].1.start,
+                            reference[consumed - 1].1.end,
+                            new_name.to_string(),
+                        ));
+                    }
+                }
+                _ => {
+                    trace!("name is unknown, removing @inheritdoc tag");
+                    updates.insert((inheritdoc.loc.start, inheritdoc.loc.end, String::new()));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{flatten::Flattener, ProjectPathsConfig};
+    use std::fs;
+
+    /// Writes the sources to the `src` directory of a new project and flattens the target offline.
+    fn flatten(sources: &[(&str, &str)], target: &str) -> String {
+        let tmp = tempfile::tempdir().unwrap();
+        let paths: ProjectPathsConfig = ProjectPathsConfig::dapptools(tmp.path()).unwrap();
+        fs::create_dir_all(&paths.sources).unwrap();
+        for (name, content) in sources {
+            fs::write(paths.sources.join(name), content).unwrap();
+        }
+        Flattener::new_offline(&paths, &paths.sources.join(target)).unwrap().flatten()
+    }
+
+    #[test]
+    fn can_flatten_complex_aliases_setup_with_duplicates() {
+        let result = flatten(
+            &[
+                (
+                    "A.sol",
+                    r"
+pragma solidity ^0.8.10;
+
+contract A {
+    type SomeCustomValue is uint256;
+
+    struct SomeStruct {
+        uint256 field;
+    }
+
+    enum SomeEnum { VALUE1, VALUE2 }
+
+    function foo() public pure returns (uint256) {
+        return 1;
+    }
+}
+",
+                ),
+                (
+                    "B.sol",
+                    r#"
+pragma solidity ^0.8.10;
+import "./A.sol" as A_File;
+
+contract A is A_File.A {}
+"#,
+                ),
+                (
+                    "C.sol",
+                    r#"
+pragma solidity ^0.8.10;
+import "./B.sol" as B_File;
+
+contract A is B_File.A_File.A {}
+"#,
+                ),
+                (
+                    "D.sol",
+                    r#"
+pragma solidity ^0.8.10;
+import * as C_File from "./C.sol";
+
+C_File.B_File.A_File.A.SomeCustomValue constant fileLevelValue = C_File.B_File.A_File.A.SomeCustomValue.wrap(1);
+
+contract D is C_File.B_File.A_File.A {
+    C_File.B_File.A_File.A.SomeStruct public someStruct;
+    C_File.B_File.A_File.A.SomeEnum public someEnum = C_File.B_File.A_File.A.SomeEnum.VALUE1;
+
+    constructor() C_File.B_File.A_File.A() {
+        someStruct = C_File.B_File.A_File.A.SomeStruct(1);
+        someEnum = C_File.B_File.A_File.A.SomeEnum.VALUE2;
+    }
+
+    function getSelector() public pure returns (bytes4) {
+        return C_File.B_File.A_File.A.foo.selector;
+    }
+
+    function getEnumValue1() public pure returns (C_File.B_File.A_File.A.SomeEnum) {
+        return C_File.B_File.A_File.A.SomeEnum.VALUE1;
+    }
+}
+"#,
+                ),
+            ],
+            "D.sol",
+        );
+        assert_eq!(
+            result,
+            r"pragma solidity ^0.8.10;
+
+// src/A.sol
+
+contract A_0 {
+    type SomeCustomValue is uint256;
+
+    struct SomeStruct {
+        uint256 field;
+    }
+
+    enum SomeEnum { VALUE1, VALUE2 }
+
+    function foo() public pure returns (uint256) {
+        return 1;
+    }
+}
+
+// src/B.sol
+
+contract A_1 is A_0 {}
+
+// src/C.sol
+
+contract A_2 is A_0 {}
+
+// src/D.sol
+
+A_0.SomeCustomValue constant fileLevelValue = A_0.SomeCustomValue.wrap(1);
+
+contract D is A_0 {
+    A_0.SomeStruct public someStruct;
+    A_0.SomeEnum public someEnum = A_0.SomeEnum.VALUE1;
+
+    constructor() A_0() {
+        someStruct = A_0.SomeStruct(1);
+        someEnum = A_0.SomeEnum.VALUE2;
+    }
+
+    function getSelector() public pure returns (bytes4) {
+        return A_0.foo.selector;
+    }
+
+    function getEnumValue1() public pure returns (A_0.SomeEnum) {
+        return A_0.SomeEnum.VALUE1;
+    }
+}
+"
+        );
+    }
+
+    #[test]
+    fn can_flatten_rename_inheritdocs() {
+        let result = flatten(
+            &[
+                ("DuplicateA.sol", "pragma solidity ^0.8.10;\ncontract A {}\n"),
+                (
+                    "A.sol",
+                    r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.10;
+import {A as OtherName} from "./DuplicateA.sol";
+
+contract A {
+    /// Documentation
+    function foo() public virtual {}
+}
+"#,
+                ),
+                (
+                    "B.sol",
+                    r#"// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.10;
+import {A} from "./A.sol";
+
+contract B is A {
+    /// @inheritdoc A
+    function foo() public override(A) {}
+
+    /** @inheritdoc Unknown */
+    function bar() public {}
+}"#,
+                ),
+            ],
+            "B.sol",
+        );
+        assert_eq!(
+            result,
+            r"// SPDX-License-Identifier: UNLICENSED
+pragma solidity ^0.8.10;
+
+// src/DuplicateA.sol
+
+contract A_0 {}
+
+// src/A.sol
+
+contract A_1 {
+    /// Documentation
+    function foo() public virtual {}
+}
+
+// src/B.sol
+
+contract B is A_1 {
+    /// @inheritdoc A_1
+    function foo() public override(A_1) {}
+
+    /**  */
+    function bar() public {}
+}
+"
+        );
+    }
+
+    #[test]
+    fn can_flatten_rename_functions_and_constants() {
+        let result = flatten(
+            &[
+                (
+                    "func1.sol",
+                    r"pragma solidity ^0.8.10;
+
+function func() view {}
+
+uint256 constant a = 1;",
+                ),
+                (
+                    "func2.sol",
+                    r"pragma solidity ^0.8.10;
+
+function func(uint256 x) view returns(uint256) {
+    return x + 1;
+}
+
+uint256 constant a = 2;",
+                ),
+                (
+                    "Target.sol",
+                    r"pragma solidity ^0.8.10;
+
+import {func as func1, a as a1} from './func1.sol';
+import {func as func2, a as a2} from './func2.sol';
+
+contract Foo {
+    uint256 func;
+
+    constructor(uint256 x) {
+        func1();
+        func2(x);
+    }
+
+    function test(uint256 a) public returns(uint256 x) {
+        assembly {
+            x := mul(a1, a2)
+        }
+        x += a + func;
+    }
+}",
+                ),
+            ],
+            "Target.sol",
+        );
+        assert_eq!(
+            result,
+            r"pragma solidity ^0.8.10;
+
+// src/func1.sol
+
+function func_0() view {}
+
+uint256 constant a_0 = 1;
+
+// src/func2.sol
+
+function func_1(uint256 x) view returns(uint256) {
+    return x + 1;
+}
+
+uint256 constant a_1 = 2;
+
+// src/Target.sol
+
+contract Foo {
+    uint256 func;
+
+    constructor(uint256 x) {
+        func_0();
+        func_1(x);
+    }
+
+    function test(uint256 a) public returns(uint256 x) {
+        assembly {
+            x := mul(a_0, a_1)
+        }
+        x += a + func;
+    }
+}
+"
+        );
+    }
+
+    #[test]
+    fn does_not_rename_attached_functions() {
+        let result = flatten(
+            &[
+                (
+                    "CustomUint.sol",
+                    r"type CustomUint is uint256;
+
+function mul(CustomUint a, CustomUint b) pure returns(CustomUint) {
+    return CustomUint.wrap(CustomUint.unwrap(a) * CustomUint.unwrap(b));
+}
+
+using {mul} for CustomUint global;",
+                ),
+                (
+                    "CustomInt.sol",
+                    r"type CustomInt is int256;
+
+function mul(CustomInt a, CustomInt b) pure returns(CustomInt) {
+    return CustomInt.wrap(CustomInt.unwrap(a) * CustomInt.unwrap(b));
+}
+
+using {mul} for CustomInt global;",
+                ),
+                (
+                    "Target.sol",
+                    r"import {CustomInt} from './CustomInt.sol';
+import {CustomUint} from './CustomUint.sol';
+
+contract Foo {
+    function mul(CustomUint a, CustomUint b) public returns(CustomUint) {
+        return a.mul(b);
+    }
+}",
+                ),
+            ],
+            "Target.sol",
+        );
+        assert!(result.contains("function mul(CustomInt a, CustomInt b)"));
+        assert!(result.contains("function mul(CustomUint a, CustomUint b) pure"));
+        assert!(result.contains("using {mul} for CustomUint global;"));
+        assert!(result.contains("return a.mul(b);"));
+    }
+
+    #[test]
+    fn cannot_flatten_invalid_source() {
+        let tmp = tempfile::tempdir().unwrap();
+        let paths: ProjectPathsConfig = ProjectPathsConfig::dapptools(tmp.path()).unwrap();
+        fs::create_dir_all(&paths.sources).unwrap();
+        let target = paths.sources.join("Contract.sol");
+        fs::write(&target, "contract Contract {\n    failure();\n}\n").unwrap();
+        assert!(Flattener::new_offline(&paths, &target).is_err());
+    }
+}
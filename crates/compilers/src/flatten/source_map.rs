@@ -0,0 +1,302 @@
+use std::{
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+/// A range of a flattened file and the range of the original source it was copied from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FlattenedSegment {
+    /// Range in the flattened file.
+    pub flattened: Range<usize>,
+    /// Path of the original source.
+    pub path: PathBuf,
+    /// Range in the original source.
+    ///
+    /// It has the same length as `flattened`, unless the segment is `renamed`.
+    pub original: Range<usize>,
+    /// Whether the segment replaces the original range, e.g. an identifier renamed during
+    /// flattening.
+    pub renamed: bool,
+}
+
+impl FlattenedSegment {
+    /// Returns true if the segment was copied without changes.
+    pub fn is_verbatim(&self) -> bool {
+        !self.renamed
+    }
+
+    /// Maps an offset inside the segment to the original source.
+    ///
+    /// Offsets inside renamed identifiers are mapped to the start of the original identifier.
+    fn original_offset(&self, offset: usize) -> usize {
+        if self.is_verbatim() {
+            self.original.start + (offset - self.flattened.start)
+        } else {
+            self.original.start
+        }
+    }
+}
+
+/// Maps ranges of a flattened file back to the sources they originate from.
+///
+/// Text generated during flattening, like file name comments and the combined pragmas and license
+/// of the target, is not part of any segment.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FlattenedSourceMap {
+    /// Segments sorted by their range in the flattened file.
+    segments: Vec<FlattenedSegment>,
+}
+
+impl FlattenedSourceMap {
+    /// Returns all segments, sorted by their range in the flattened file.
+    pub fn segments(&self) -> &[FlattenedSegment] {
+        &self.segments
+    }
+
+    /// Returns the segment containing the offset of the flattened file.
+    pub fn find(&self, offset: usize) -> Option<&FlattenedSegment> {
+        let idx = self.segments.partition_point(|segment| segment.flattened.end <= offset);
+        self.segments.get(idx).filter(|segment| segment.flattened.contains(&offset))
+    }
+
+    /// Maps the offset of the flattened file to the original source and the offset in it.
+    ///
+    /// Returns `None` if the offset is in generated text.
+    pub fn original_offset(&self, offset: usize) -> Option<(&Path, usize)> {
+        let segment = self.find(offset)?;
+        Some((&segment.path, segment.original_offset(offset)))
+    }
+
+    /// Maps the non-empty range of the flattened file to the original source and the range in it.
+    ///
+    /// Returns `None` if the range starts or ends in generated text, or spans multiple sources.
+    pub fn original_range(&self, range: Range<usize>) -> Option<(&Path, Range<usize>)> {
+        if range.is_empty() {
+            return None;
+        }
+        let first = self.find(range.start)?;
+        let last = self.find(range.end - 1)?;
+        if first.path != last.path {
+            return None;
+        }
+        let end = if last.is_verbatim() {
+            last.original_offset(range.end - 1) + 1
+        } else {
+            last.original.end
+        };
+        Some((&first.path, first.original_offset(range.start)..end))
+    }
+
+    /// Appends the segments of a source, shifted by the offset the source starts at.
+    pub(super) fn extend(&mut self, segments: &[FlattenedSegment], offset: usize) {
+        self.segments.extend(segments.iter().map(|segment| FlattenedSegment {
+            flattened: segment.flattened.start + offset..segment.flattened.end + offset,
+            ..segment.clone()
+        }));
+    }
+
+    /// Removes the sorted and non-overlapping ranges from the text and updates the segments
+    /// accordingly.
+    pub(super) fn remove_ranges(&mut self, text: &str, ranges: &[Range<usize>]) -> String {
+        // `removed[i]` is the total length of the first `i` ranges
+        let removed = std::iter::once(0)
+            .chain(ranges.iter().scan(0, |removed, range| {
+                *removed += range.len();
+                Some(*removed)
+            }))
+            .collect::<Vec<_>>();
+        // only used for offsets outside of the ranges
+        let shift =
+            |offset: usize| offset - removed[ranges.partition_point(|range| range.end <= offset)];
+
+        let mut segments = Vec::with_capacity(self.segments.len());
+        for segment in &self.segments {
+            let first = ranges.partition_point(|range| range.end <= segment.flattened.start);
+            let mut start = segment.flattened.start;
+            for range in
+                ranges[first..].iter().take_while(|range| range.start < segment.flattened.end)
+            {
+                if start < range.start {
+                    segments.push(piece(segment, start..range.start, &shift));
+                }
+                start = start.max(range.end);
+            }
+            if start < segment.flattened.end {
+                segments.push(piece(segment, start..segment.flattened.end, &shift));
+            }
+        }
+        self.segments = segments;
+
+        let mut result = String::with_capacity(text.len());
+        let mut start = 0;
+        for range in ranges {
+            result.push_str(&text[start..range.start]);
+            start = range.end;
+        }
+        result.push_str(&text[start..]);
+        result
+    }
+}
+
+/// Returns the part of the segment at `range`, moved via `shift`.
+fn piece(
+    segment: &FlattenedSegment,
+    range: Range<usize>,
+    shift: &impl Fn(usize) -> usize,
+) -> FlattenedSegment {
+    let original = if segment.is_verbatim() {
+        segment.original_offset(range.start)..segment.original_offset(range.end - 1) + 1
+    } else {
+        segment.original.clone()
+    };
+    let start = shift(range.start);
+    FlattenedSegment {
+        flattened: start..start + range.len(),
+        path: segment.path.clone(),
+        original,
+        renamed: segment.renamed,
+    }
+}
+
+/// Applies the sorted updates to the content of the source, like
+/// [`replace_source_content`](crate::replace_source_content), and returns the updated content
+/// with segments mapping it to the original content.
+///
+/// Inserted text is not mapped, and replaced text is mapped to the range it replaces.
+pub(super) fn apply_updates<'a>(
+    path: &Path,
+    content: &str,
+    updates: impl IntoIterator<Item = &'a (usize, usize, String)>,
+) -> (String, Vec<FlattenedSegment>) {
+    let mut result = String::with_capacity(content.len());
+    let mut segments = Vec::new();
+    let mut segment = |result: &String, len: usize, original: Range<usize>, renamed: bool| {
+        if len > 0 && !original.is_empty() {
+            segments.push(FlattenedSegment {
+                flattened: result.len()..result.len() + len,
+                path: path.to_path_buf(),
+                original,
+                renamed,
+            });
+        }
+    };
+
+    let mut pos = 0;
+    for (start, end, new_value) in updates {
+        if *start > pos {
+            segment(&result, start - pos, pos..*start, false);
+            result.push_str(&content[pos..*start]);
+        }
+        segment(&result, new_value.len(), *start..*end, true);
+        result.push_str(new_value);
+        pos = pos.max(*end);
+    }
+    segment(&result, content.len() - pos, pos..content.len(), false);
+    result.push_str(&content[pos..]);
+
+    (result, segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{flatten::Flattener, ProjectPathsConfig};
+    use std::fs;
+
+    #[test]
+    fn can_map_flattened_ranges() {
+        let tmp = tempfile::tempdir().unwrap();
+        let paths: ProjectPathsConfig = ProjectPathsConfig::dapptools(tmp.path()).unwrap();
+        fs::create_dir_all(&paths.sources).unwrap();
+        let a = paths.sources.join("A.sol");
+        let a_content = r"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.10;
+
+contract A {}
+
+
+
+
+uint256 constant C = 1;
+";
+        fs::write(&a, a_content).unwrap();
+        let b = paths.sources.join("B.sol");
+        let b_content = r#"pragma solidity ^0.8.10;
+import {A as Base} from "./A.sol";
+import "./A.sol" as L;
+
+contract A is Base {
+    uint256 x;
+    L.A a;
+}
+"#;
+        fs::write(&b, b_content).unwrap();
+
+        let (result, source_map) =
+            Flattener::new_offline(&paths, &b).unwrap().flatten_with_source_map();
+        assert_eq!(
+            result,
+            r"pragma solidity ^0.8.10;
+
+// src/A.sol
+
+contract A_0 {}
+
+uint256 constant C = 1;
+
+// src/B.sol
+
+contract A_1 is A_0 {
+    uint256 x;
+    A_0 a;
+}
+"
+        );
+
+        for segment in source_map.segments().iter().filter(|segment| segment.is_verbatim()) {
+            let content = if segment.path == a { a_content } else { b_content };
+            assert_eq!(&result[segment.flattened.clone()], &content[segment.original.clone()]);
+        }
+
+        let find = |content: &str, text: &str| {
+            let start = content.find(text).unwrap();
+            start..start + text.len()
+        };
+        assert_eq!(
+            source_map.original_range(find(&result, "A_0 {}")),
+            Some((a.as_path(), find(a_content, "A {}")))
+        );
+        assert_eq!(
+            source_map.original_range(find(&result, "uint256 constant C")),
+            Some((a.as_path(), find(a_content, "uint256 constant C")))
+        );
+        // the aliased reference, not the import
+        let base = result.find("is A_0").unwrap() + 3;
+        let original_base = b_content.rfind("Base").unwrap();
+        assert_eq!(
+            source_map.original_range(base..base + 3),
+            Some((b.as_path(), original_base..original_base + 4))
+        );
+        // renamed to a name of the same length
+        assert_eq!(
+            source_map.original_range(find(&result, "A_0 a")),
+            Some((b.as_path(), find(b_content, "L.A a")))
+        );
+        assert_eq!(
+            source_map.original_offset(find(&result, "A_0 a").start + 2),
+            Some((b.as_path(), find(b_content, "L.A a").start))
+        );
+        assert_eq!(
+            source_map.original_offset(find(&result, "uint256 x").start),
+            Some((b.as_path(), find(b_content, "uint256 x").start))
+        );
+
+        // generated pragma and file name comments
+        assert_eq!(source_map.original_offset(0), None);
+        assert_eq!(source_map.original_offset(find(&result, "// src/B.sol").start), None);
+        // spans multiple sources
+        assert_eq!(
+            source_map.original_range(find(&result, "C = 1;\n\n// src/B.sol\n\ncontract")),
+            None
+        );
+    }
+}
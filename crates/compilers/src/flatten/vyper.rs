@@ -0,0 +1,385 @@
+use crate::{
+    compilers::vyper::{
+        parser::VyperParsedSource, VyperInput, VyperLanguage, VyperSettings,
+        VYPER_INTERFACE_EXTENSION,
+    },
+    resolver::GraphEdges,
+    Graph, ProjectPathsConfig,
+};
+use foundry_compilers_artifacts::sources::{Source, Sources};
+use foundry_compilers_core::error::{Result, SolcError};
+use semver::{Op, VersionReq};
+use std::path::Path;
+
+/// Vyper contract prepared for verification, see [`ProjectPathsConfig::flatten`].
+#[derive(Clone, Debug)]
+pub enum VyperFlattened {
+    /// Source of a contract which does not import other files, or only imports interfaces.
+    ///
+    /// Imported interfaces are inlined as `interface` blocks, if they only declare external
+    /// functions.
+    Source(String),
+    /// Standard JSON input with the contract and all modules and interfaces it imports.
+    ///
+    /// Modules can't be inlined, because their members are accessed via the module namespace.
+    /// Paths are relative to the project root, and settings other than the search paths are left
+    /// to the caller.
+    StandardJson(VyperInput),
+}
+
+impl VyperFlattened {
+    /// Resolves the imports of the target file. Built-in interfaces, like `ethereum.ercs`, are not
+    /// considered imports.
+    ///
+    /// Which imports are interfaces depends on the version requirement of the target: modules were
+    /// introduced in Vyper 0.4.0, so all imported files are interfaces before, and only `.vyi`
+    /// files since.
+    pub fn new(paths: &ProjectPathsConfig<VyperLanguage>, target: &Path) -> Result<Self> {
+        let sources = Source::read_all_files(vec![target.to_path_buf()])?;
+        let graph = Graph::<VyperParsedSource>::resolve_sources(paths, sources)?;
+        let (sources, edges) = graph.into_sources();
+
+        let source = sources.get(target).ok_or_else(|| {
+            SolcError::msg(format!("cannot resolve file at {}", target.display()))
+        })?;
+        if edges.imports(target).is_empty() {
+            return Ok(Self::Source(source.content.to_string()));
+        }
+        let supports_modules = supports_modules(version_req(&source.content).as_ref());
+        if let Some(flattened) = inline_interfaces(target, &sources, &edges, supports_modules) {
+            return Ok(Self::Source(flattened));
+        }
+
+        let mut input = VyperInput {
+            language: "Vyper".to_string(),
+            sources: Sources::new(),
+            interfaces: Sources::new(),
+            settings: VyperSettings::default(),
+        };
+        // the graph only contains the target and its imports
+        for (path, source) in sources {
+            if path.extension().is_some_and(|ext| ext == VYPER_INTERFACE_EXTENSION) {
+                input.interfaces.insert(path, source);
+            } else {
+                input.sources.insert(path, source);
+            }
+        }
+        let mut search_paths = paths.include_paths.clone();
+        search_paths.extend(edges.include_paths().iter().cloned());
+        input.settings.search_paths = Some(search_paths);
+        input.strip_prefix(&paths.root);
+
+        Ok(Self::StandardJson(input))
+    }
+}
+
+/// Returns the version requirement of the `# pragma version` or `# @version` comment.
+fn version_req(content: &str) -> Option<VersionReq> {
+    content.lines().find_map(|line| {
+        let comment = line.strip_prefix('#')?.trim_start();
+        let version =
+            comment.strip_prefix("pragma version").or_else(|| comment.strip_prefix("@version"))?;
+        VersionReq::parse(version.trim()).ok()
+    })
+}
+
+/// Returns whether the version requirement allows Vyper 0.4.0 or later, which supports modules.
+///
+/// Sources without a version requirement are assumed to target the latest version.
+fn supports_modules(version_req: Option<&VersionReq>) -> bool {
+    let Some(req) = version_req else { return true };
+    !req.comparators.iter().any(|comparator| {
+        let minor = comparator.minor.unwrap_or(u64::MAX);
+        comparator.major == 0
+            && match comparator.op {
+                Op::Exact | Op::Tilde | Op::Caret | Op::LessEq | Op::Wildcard => minor < 4,
+                Op::Less => minor < 4 || (minor == 4 && comparator.patch.unwrap_or(0) == 0),
+                _ => false,
+            }
+    })
+}
+
+/// Replaces the imports of the target with inline `interface` blocks.
+///
+/// Returns `None` if the target imports a module, or an interface that declares anything but
+/// external functions or imports other files.
+fn inline_interfaces(
+    target: &Path,
+    sources: &Sources,
+    edges: &GraphEdges<VyperParsedSource>,
+    supports_modules: bool,
+) -> Option<String> {
+    let imports = edges.imports(target);
+    let is_interface = |path: &Path| {
+        !supports_modules || path.extension().is_some_and(|ext| ext == VYPER_INTERFACE_EXTENSION)
+    };
+    if !imports.iter().all(|path| is_interface(path) && edges.imports(path).is_empty()) {
+        return None;
+    }
+
+    let mut inlined = 0;
+    let mut result = String::new();
+    for line in sources.get(target)?.content.split_inclusive('\n') {
+        let Some((name, binding)) = parse_import(line) else {
+            result.push_str(line);
+            continue;
+        };
+        // the file is identified by its name, so it must be unique
+        let mut matching =
+            imports.iter().filter(|path| path.file_stem().is_some_and(|stem| stem == name));
+        let (Some(path), None) = (matching.next(), matching.next()) else { return None };
+        result.push_str(&inline_interface(binding, &sources.get(*path)?.content)?);
+        inlined += 1;
+    }
+    (inlined == imports.len()).then_some(result)
+}
+
+/// Parses the import statement on the line and returns the name of the imported file and the name
+/// it is bound to.
+///
+/// Returns `None` for other lines and built-in interfaces.
+fn parse_import(line: &str) -> Option<(&str, &str)> {
+    let line = line.split('#').next().unwrap_or_default();
+    let (path, rest) = match line.split_whitespace().collect::<Vec<_>>().as_slice() {
+        ["from", module, "import", rest @ ..] => (*module, rest.to_vec()),
+        ["import", path, rest @ ..] => (*path, [&[*path][..], rest].concat()),
+        _ => return None,
+    };
+    let path = path.trim_start_matches('.');
+    if path.starts_with("ethereum.ercs") || path.starts_with("vyper.") {
+        return None;
+    }
+    let (name, binding) = match rest.as_slice() {
+        [name] => (*name, name.rsplit('.').next()?),
+        [name, "as", alias] => (*name, *alias),
+        _ => return None,
+    };
+    Some((name.rsplit('.').next()?, binding))
+}
+
+/// Converts the content of an interface file into an inline `interface` block, e.g.
+///
+/// ```vyper
+/// @external
+/// @view
+/// def balanceOf(owner: address) -> uint256:
+///     ...
+/// ```
+///
+/// becomes `def balanceOf(owner: address) -> uint256: view` in the block. Bodies are ignored, so
+/// pre-0.4.0 interfaces, which use `pass` or full implementations, are supported as well.
+fn inline_interface(name: &str, content: &str) -> Option<String> {
+    let mut block = format!("interface {name}:\n");
+    let mut decorators = Vec::new();
+    let mut lines = content.lines();
+    let mut has_functions = false;
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || line.starts_with('#') || line.starts_with(char::is_whitespace) {
+            // bodies of functions, which are skipped
+            continue;
+        }
+        if let Some(decorator) = trimmed.strip_prefix('@') {
+            decorators.push(decorator.trim().to_string());
+            continue;
+        }
+        if !trimmed.starts_with("def ") {
+            return None;
+        }
+
+        // the signature ends at the first colon outside of brackets
+        let mut signature = String::new();
+        let mut depth = 0usize;
+        let mut rest = trimmed;
+        'signature: loop {
+            for (idx, c) in rest.char_indices() {
+                match c {
+                    '(' | '[' => depth += 1,
+                    ')' | ']' => depth = depth.saturating_sub(1),
+                    ':' if depth == 0 => {
+                        signature.push_str(rest[..idx].trim_end());
+                        break 'signature;
+                    }
+                    _ => {}
+                }
+            }
+            signature.push_str(rest);
+            rest = lines.next()?.trim();
+            if !signature.ends_with('(') && !rest.starts_with(')') {
+                signature.push(' ');
+            }
+        }
+
+        let decorators = std::mem::take(&mut decorators);
+        if !decorators.iter().any(|decorator| decorator == "external") {
+            continue;
+        }
+        let mutability = ["view", "pure", "payable"]
+            .into_iter()
+            .find(|mutability| decorators.iter().any(|decorator| decorator == mutability))
+            .unwrap_or("nonpayable");
+        block.push_str(&format!("    {signature}: {mutability}\n"));
+        has_functions = true;
+    }
+    has_functions.then_some(block)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs, path::PathBuf};
+
+    #[test]
+    fn can_flatten_vyper() {
+        let tmp = tempfile::tempdir().unwrap();
+        let paths: ProjectPathsConfig<VyperLanguage> =
+            ProjectPathsConfig::dapptools(tmp.path()).unwrap();
+        fs::create_dir_all(paths.sources.join("interfaces")).unwrap();
+        let write = |name: &str, content: &str| {
+            let path = paths.sources.join(name);
+            fs::write(&path, content).unwrap();
+            path
+        };
+
+        let token = r"# pragma version ^0.4.0
+from ethereum.ercs import IERC20
+
+implements: IERC20
+";
+        let target = write("Token.vy", token);
+        match paths.flatten(&target).unwrap() {
+            VyperFlattened::Source(source) => assert_eq!(source, token),
+            flattened => panic!("unexpected {flattened:?}"),
+        }
+
+        write(
+            "interfaces/ICounter.vyi",
+            r"# pragma version ^0.4.0
+
+@external
+def increment():
+    ...
+
+@view
+@external
+def add(
+    a: uint256,
+    b: uint256
+) -> uint256:
+    ...
+",
+        );
+        let target = write(
+            "Caller.vy",
+            r"# pragma version ^0.4.0
+from ethereum.ercs import IERC20
+from .interfaces import ICounter
+
+@external
+def call(counter: ICounter):
+    extcall counter.increment()
+",
+        );
+        match paths.flatten(&target).unwrap() {
+            VyperFlattened::Source(source) => assert_eq!(
+                source,
+                r"# pragma version ^0.4.0
+from ethereum.ercs import IERC20
+interface ICounter:
+    def increment(): nonpayable
+    def add(a: uint256, b: uint256) -> uint256: view
+
+@external
+def call(counter: ICounter):
+    extcall counter.increment()
+"
+            ),
+            flattened => panic!("unexpected {flattened:?}"),
+        }
+
+        // imported `.vy` files are interfaces before 0.4.0
+        write(
+            "interfaces/IOwned.vy",
+            r"owner: public(address)
+
+@external
+def transfer(owner: address):
+    pass
+",
+        );
+        write(
+            "interfaces/IPausable.vy",
+            "@external\n@payable\ndef pause(): pass\n\n@internal\ndef _check():\n    pass\n",
+        );
+        let legacy = r"# @version ^0.3.10
+import interfaces.IPausable as Pausable
+
+implements: Pausable
+";
+        let target = write("Legacy.vy", legacy);
+        match paths.flatten(&target).unwrap() {
+            VyperFlattened::Source(source) => assert_eq!(
+                source,
+                r"# @version ^0.3.10
+interface Pausable:
+    def pause(): payable
+
+implements: Pausable
+"
+            ),
+            flattened => panic!("unexpected {flattened:?}"),
+        }
+        // interfaces declaring more than external functions aren't inlined
+        let target = write("Owned.vy", "# @version ^0.3.10\nimport interfaces.IOwned as IOwned\n");
+        assert!(matches!(paths.flatten(&target).unwrap(), VyperFlattened::StandardJson(_)));
+
+        write(
+            "Math.vy",
+            "@internal\ndef add(a: uint256, b: uint256) -> uint256:\n    return a + b\n",
+        );
+        let target = write(
+            "Counter.vy",
+            r"# pragma version ^0.4.0
+from ethereum.ercs import IERC20
+from . import Math
+from .interfaces import ICounter
+
+implements: ICounter
+
+count: public(uint256)
+
+@external
+def increment():
+    self.count = Math.add(self.count, 1)
+",
+        );
+        let VyperFlattened::StandardJson(input) = paths.flatten(&target).unwrap() else {
+            panic!("expected standard JSON input");
+        };
+        assert_eq!(
+            input.sources.keys().collect::<Vec<_>>(),
+            [Path::new("src/Counter.vy"), Path::new("src/Math.vy")]
+        );
+        assert_eq!(
+            input.interfaces.keys().collect::<Vec<_>>(),
+            [Path::new("src/interfaces/ICounter.vyi")]
+        );
+        assert!(input.settings.search_paths.unwrap().contains(&PathBuf::from("src")));
+
+        write("Broken.vy", "from . import Missing\n");
+        assert!(paths.flatten(&paths.sources.join("Broken.vy")).is_err());
+    }
+
+    #[test]
+    fn can_detect_module_support() {
+        let supports_modules = |req: &str| supports_modules(Some(&req.parse().unwrap()));
+        assert!(supports_modules("^0.4.0"));
+        assert!(supports_modules(">=0.3.10"));
+        assert!(supports_modules("=0.4.1"));
+        assert!(!supports_modules("^0.3.10"));
+        assert!(!supports_modules("=0.3.9"));
+        assert!(!supports_modules(">=0.3.0, <0.4.0"));
+        assert!(!supports_modules("0.3.*"));
+        assert!(super::supports_modules(None));
+    }
+}
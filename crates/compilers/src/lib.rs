@@ -32,7 +32,10 @@ mod config;
 pub use config::{PathStyle, ProjectPaths, ProjectPathsConfig, SolcConfig};
 
 mod filter;
-pub use filter::{FileFilter, SparseOutputFilter, TestFileFilter};
+pub use filter::{
+    ContractNameFilter, FileFilter, GlobFileFilter, SourceFilter, SparseOutputFilter,
+    TestFileFilter,
+};
 
 pub mod report;
 
@@ -50,18 +53,25 @@ pub use foundry_compilers_core::{error, utils};
 
 use cache::CompilerCache;
 use compile::output::contracts::VersionedContracts;
-use compilers::multi::MultiCompiler;
+use compilers::{
+    multi::{MultiCompiler, MultiCompilerLanguage, MultiCompilerParsedSource, SolidityCompiler},
+    resolc::{ResolcStandardJsonInput, ResolcVersionedInput},
+    solc::SolcVersionedInput,
+};
 
 use foundry_compilers_artifacts::{
     output_selection::OutputSelection,
+    resolc::libraries::PolkaVmLibraries,
     solc::{
         sources::{Source, SourceCompilationKind, Sources},
-        Severity, SourceFile, StandardJsonCompilerInput,
+        Libraries, Severity, SourceFile, StandardJsonCompilerInput,
     },
+    Contract,
 };
 use foundry_compilers_core::error::{Result, SolcError, SolcIoError};
 use output::sources::{VersionedSourceFile, VersionedSourceFiles};
-use project::ProjectCompiler;
+use project::{CompilerJobLimits, ProjectCompiler};
+use resolver::ResolvedSources;
 use semver::Version;
 use solar_parse::Parser;
 use solar_sema::interface::{diagnostics::EmittedDiagnostics, source_map::FileName, Session};
@@ -109,6 +119,8 @@ pub struct Project<
     pub compiler_severity_filter: Severity,
     /// Maximum number of `solc` processes to run simultaneously.
     solc_jobs: usize,
+    /// Additional limits for compiler processes running simultaneously.
+    pub job_limits: CompilerJobLimits,
     /// Offline mode, if set, network access (download solc) is disallowed
     pub offline: bool,
     /// Windows only config value to ensure the all paths use `/` instead of `\\`, same as `solc`
@@ -211,6 +223,136 @@ where
     }
 }
 
+impl<T: ArtifactOutput<CompilerContract = Contract>> Project<MultiCompiler, T> {
+    /// Returns the standard JSON input `resolc` is invoked with to compile the target contract,
+    /// together with the `resolc` and `solc` versions.
+    ///
+    /// Unlike [`Self::standard_json_input`], the input contains all sources compiled together
+    /// with the target, and the `solc` version and the settings profile are resolved like during
+    /// compilation. The input is the one recorded in the build info of a build without cached
+    /// artifacts, see [`ResolcStandardJsonInput::from_build_info`].
+    pub fn resolc_standard_json_input(&self, target: &Path) -> Result<ResolcStandardJsonInput> {
+        trace!(?target, "Building resolc standard-json-input");
+        let (SolidityCompiler::Resolc(resolc) | SolidityCompiler::Dual(resolc)) =
+            &self.compiler.solidity
+        else {
+            return Err(SolcError::msg("project is not configured to compile with resolc"));
+        };
+
+        let graph = Graph::<MultiCompilerParsedSource>::resolve(&self.paths)?;
+        let ResolvedSources { sources, edges, .. } = graph.into_sources_by_version(self)?;
+        // with multiple targets, the PolkaVM profile of the target has to be used
+        let dual = self.compiler.targets().len() > 1;
+        let (language, version, sources, settings) = sources
+            .into_iter()
+            .flat_map(|(language, sources)| sources.into_iter().map(move |s| (language, s)))
+            .find_map(|(language, (version, sources, (_, settings)))| {
+                let MultiCompilerLanguage::Solc(language) = language else { return None };
+                (sources.contains_key(target)
+                    && (!dual || settings.target() == CompilationTarget::PolkaVm))
+                    .then_some((language, version, sources, settings))
+            })
+            .ok_or_else(|| {
+                SolcError::msg(format!("cannot resolve file at {:?}", target.display()))
+            })?;
+
+        let mut settings = settings.solc.clone();
+        SparseOutputFilter::new(self.sparse_output.as_deref()).sparse_sources(
+            &sources,
+            &mut settings,
+            &edges,
+        );
+        let mut include_paths = self.paths.include_paths.clone();
+        include_paths.extend(edges.include_paths().clone());
+        let settings = settings
+            .with_base_path(&self.paths.root)
+            .with_allow_paths(&self.paths.allowed_paths)
+            .with_include_paths(&include_paths)
+            .with_remappings(&self.paths.remappings);
+
+        let mut input = SolcVersionedInput::build(sources, settings, language, version);
+        input.strip_prefix(self.root());
+        let input = ResolcVersionedInput::from(input);
+        // the binary was resolved while grouping the sources by version
+        let resolc_version = resolc
+            .find_for_input(&input)?
+            .map(|resolc| resolc.resolc_version.clone())
+            .ok_or_else(|| {
+                SolcError::msg(format!(
+                    "no installed `resolc` satisfies the requirements of {}",
+                    target.display()
+                ))
+            })?;
+
+        Ok(ResolcStandardJsonInput {
+            resolc_version,
+            solc_version: input.solc_version,
+            input: input.input,
+        })
+    }
+
+    /// Links the unlinked PolkaVM contracts with the addresses of their libraries, see
+    /// [`ProjectCompileOutput::unlinked_polkavm_artifacts`].
+    ///
+    /// `resolc` embeds library addresses during compilation, so the sources of the contracts are
+    /// recompiled with the linked libraries added to the `libraries` setting of all profiles, and
+    /// the output contains the linked artifacts. Returns an error if a library of the contracts
+    /// isn't linked, or the project isn't configured to compile with `resolc`.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use alloy_primitives::Address;
+    /// use foundry_compilers::Project;
+    ///
+    /// let project = Project::builder().build(Default::default())?;
+    /// let output = project.compile()?;
+    /// let mut unlinked = output.unlinked_polkavm_artifacts();
+    /// for (_, libraries) in &mut unlinked {
+    ///     libraries.link_fully_qualified("src/Math.sol:Math", Address::ZERO);
+    /// }
+    /// let linked = project.link_polkavm(&unlinked)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn link_polkavm(
+        &self,
+        contracts: &[(ArtifactId, PolkaVmLibraries)],
+    ) -> Result<ProjectCompileOutput<MultiCompiler, T>>
+    where
+        T: Clone,
+    {
+        if !self.compiler.targets().contains(&CompilationTarget::PolkaVm) {
+            return Err(SolcError::msg("project is not configured to compile with resolc"));
+        }
+
+        let mut sources = BTreeSet::new();
+        let mut libraries = Libraries::default();
+        for (id, contract_libraries) in contracts {
+            if contract_libraries.has_missing() {
+                let missing = contract_libraries.missing_libraries().collect::<Vec<_>>();
+                return Err(SolcError::msg(format!(
+                    "cannot link {}, missing addresses of libraries: {}",
+                    id.identifier(),
+                    missing.join(", ")
+                )));
+            }
+            for (file, libs) in contract_libraries.libraries().libs {
+                libraries.libs.entry(file).or_default().extend(libs);
+            }
+            sources.insert(self.root().join(&id.source));
+        }
+
+        let mut project = self.clone();
+        for settings in
+            std::iter::once(&mut project.settings).chain(project.additional_settings.values_mut())
+        {
+            for (file, libs) in &libraries.libs {
+                settings.solc.libraries.libs.entry(file.clone()).or_default().extend(libs.clone());
+            }
+        }
+        project.compile_files(sources)
+    }
+}
+
 impl<T: ArtifactOutput<CompilerContract = C::CompilerContract>, C: Compiler> Project<C, T> {
     /// Returns the path to the artifacts directory
     pub fn artifacts_path(&self) -> &Path {
@@ -433,6 +575,60 @@ impl<T: ArtifactOutput<CompilerContract = C::CompilerContract>, C: Compiler> Pro
             s.update_output_selection(f);
         });
     }
+
+    /// Sets the compiler and adds the profiles for its targets, see [Self::add_target_profiles].
+    pub fn set_compiler(&mut self, compiler: C) {
+        self.compiler = compiler;
+        self.add_target_profiles();
+    }
+
+    /// Adds a twin profile for every additional target of the compiler, see
+    /// [Compiler::targets].
+    ///
+    /// Every profile targeting the primary target gets a twin for each other target, named after
+    /// the target for the default profile (e.g. `polkavm`) and `{profile}-{target}` otherwise.
+    /// Existing profiles with the same name are replaced. This is a noop for single-target
+    /// compilers.
+    ///
+    /// This is done by [ProjectBuilder::build] and [Self::set_compiler], and has to be repeated
+    /// when the compiler or the settings are changed directly.
+    pub fn add_target_profiles(&mut self) {
+        let twins = self.target_profiles();
+        self.additional_settings.extend(twins);
+    }
+
+    /// Returns the names of the profiles added by [Self::add_target_profiles] that are missing.
+    pub fn missing_target_profiles(&self) -> Vec<String> {
+        self.target_profiles()
+            .into_iter()
+            .filter(|(name, settings)| {
+                self.additional_settings
+                    .get(name)
+                    .is_none_or(|existing| existing.target() != settings.target())
+            })
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    /// Returns the twin profiles of [Self::add_target_profiles].
+    fn target_profiles(&self) -> Vec<(String, C::Settings)> {
+        let targets = self.compiler.targets();
+        let Some((primary, others)) = targets.split_first() else { return Vec::new() };
+
+        self.settings_profiles()
+            .filter(|(_, settings)| settings.target() == *primary)
+            .flat_map(|(name, settings)| {
+                others.iter().map(move |target| {
+                    let twin = if name == "default" {
+                        target.to_string()
+                    } else {
+                        format!("{name}-{target}")
+                    };
+                    (twin, settings.clone().with_target(*target))
+                })
+            })
+            .collect()
+    }
 }
 
 pub struct ProjectBuilder<
@@ -465,6 +661,8 @@ pub struct ProjectBuilder<
     /// The minimum severity level that is treated as a compiler error
     compiler_severity_filter: Severity,
     solc_jobs: Option<usize>,
+    /// Additional limits for parallel compiler processes
+    job_limits: CompilerJobLimits,
     /// Optional sparse output filter used to optimize compilation.
     sparse_output: Option<Box<dyn FileFilter>>,
 }
@@ -484,6 +682,7 @@ impl<C: Compiler, T: ArtifactOutput<CompilerContract = C::CompilerContract>> Pro
             ignored_file_paths: Vec::new(),
             compiler_severity_filter: Severity::Error,
             solc_jobs: None,
+            job_limits: Default::default(),
             settings: None,
             sparse_output: None,
             additional_settings: BTreeMap::new(),
@@ -603,6 +802,36 @@ impl<C: Compiler, T: ArtifactOutput<CompilerContract = C::CompilerContract>> Pro
         self.solc_jobs(1)
     }
 
+    /// Sets the maximum number of parallel processes of the compiler with the given
+    /// [`Compiler::job_key`], e.g. [`Resolc::JOB_KEY`](crate::resolc::Resolc::JOB_KEY), on top of
+    /// [`Self::solc_jobs`].
+    ///
+    /// # Panics
+    ///
+    /// `jobs` must be at least 1
+    #[must_use]
+    pub fn compiler_jobs(mut self, name: impl Into<String>, jobs: usize) -> Self {
+        assert!(jobs > 0);
+        self.job_limits.jobs.insert(name.into(), jobs);
+        self
+    }
+
+    /// Sets the estimated peak memory of a single process of the compiler with the given
+    /// [`Compiler::job_key`], counted against the [`Self::memory_budget`].
+    #[must_use]
+    pub fn compiler_job_memory(mut self, name: impl Into<String>, bytes: u64) -> Self {
+        self.job_limits.job_memory.insert(name.into(), bytes);
+        self
+    }
+
+    /// Sets the maximum estimated memory of all parallel compiler processes, see
+    /// [`CompilerJobLimits::memory_budget`].
+    #[must_use]
+    pub fn memory_budget(mut self, bytes: u64) -> Self {
+        self.job_limits.memory_budget = Some(bytes);
+        self
+    }
+
     #[must_use]
     pub fn sparse_output<F>(mut self, filter: F) -> Self
     where
@@ -642,6 +871,7 @@ impl<C: Compiler, T: ArtifactOutput<CompilerContract = C::CompilerContract>> Pro
             ignored_error_codes,
             compiler_severity_filter,
             solc_jobs,
+            job_limits,
             offline,
             build_info,
             slash_paths,
@@ -665,6 +895,7 @@ impl<C: Compiler, T: ArtifactOutput<CompilerContract = C::CompilerContract>> Pro
             ignored_file_paths,
             compiler_severity_filter,
             solc_jobs,
+            job_limits,
             build_info,
             settings,
             sparse_output,
@@ -681,6 +912,7 @@ impl<C: Compiler, T: ArtifactOutput<CompilerContract = C::CompilerContract>> Pro
             ignored_file_paths,
             compiler_severity_filter,
             solc_jobs,
+            job_limits,
             offline,
             build_info,
             slash_paths,
@@ -697,7 +929,7 @@ impl<C: Compiler, T: ArtifactOutput<CompilerContract = C::CompilerContract>> Pro
             paths.slash_paths();
         }
 
-        Ok(Project {
+        let mut project = Project {
             compiler,
             paths,
             cached,
@@ -710,13 +942,17 @@ impl<C: Compiler, T: ArtifactOutput<CompilerContract = C::CompilerContract>> Pro
             solc_jobs: solc_jobs
                 .or_else(|| std::thread::available_parallelism().ok().map(|n| n.get()))
                 .unwrap_or(1),
+            job_limits,
             offline,
             slash_paths,
             settings: settings.unwrap_or_default(),
             sparse_output,
             additional_settings,
             restrictions,
-        })
+        };
+        project.add_target_profiles();
+
+        Ok(project)
     }
 }
 
@@ -949,6 +1185,9 @@ mod tests {
     use foundry_compilers_core::utils::{self, mkdir_or_touch, tempdir};
 
     use super::*;
+    use multi::MultiCompilerSettings;
+    use resolc::Resolc;
+    use solc::{Solc, SolcCompiler};
 
     #[test]
     #[cfg_attr(windows, ignore = "<0.7 solc is flaky")]
@@ -969,6 +1208,170 @@ mod tests {
         assert_eq!(contracts.contracts().count(), 3);
     }
 
+    #[test]
+    fn can_add_target_profiles() {
+        let solc = SolcCompiler::Specific(Solc::new_with_version("solc", Version::new(0, 8, 28)));
+        let resolc = Resolc {
+            resolc: "resolc".into(),
+            resolc_version: Version::new(0, 1, 0),
+            supported_solc_versions: "^0.8.0".parse().unwrap(),
+            solc,
+            locator: Default::default(),
+        };
+        let mut optimized = MultiCompilerSettings::default();
+        optimized.solc.settings.optimizer.runs = Some(1);
+
+        let mut project = Project::builder()
+            .paths(ProjectPathsConfig::builder().build_with_root("."))
+            .additional_settings(BTreeMap::from([("optimized".to_string(), optimized)]))
+            .build(MultiCompiler {
+                solidity: multi::SolidityCompiler::Dual(resolc),
+                ..Default::default()
+            })
+            .unwrap();
+
+        let profiles = project
+            .settings_profiles()
+            .map(|(name, settings)| (name.to_string(), settings.target()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            profiles,
+            [
+                ("default".to_string(), CompilationTarget::Evm),
+                ("optimized".to_string(), CompilationTarget::Evm),
+                ("optimized-polkavm".to_string(), CompilationTarget::PolkaVm),
+                ("polkavm".to_string(), CompilationTarget::PolkaVm),
+            ]
+        );
+        assert_eq!(project.additional_settings["optimized-polkavm"].solc.optimizer.runs, Some(1));
+
+        // adding the profiles again replaces the twins
+        project.add_target_profiles();
+        assert_eq!(project.settings_profiles().count(), 4);
+        assert!(project.missing_target_profiles().is_empty());
+
+        project.additional_settings.insert("extra".to_string(), MultiCompilerSettings::default());
+        assert_eq!(project.missing_target_profiles(), ["extra-polkavm"]);
+        project.set_compiler(MultiCompiler::default());
+        assert!(project.missing_target_profiles().is_empty());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn can_link_polkavm_contracts() {
+        use alloy_primitives::Address;
+        use std::{fs, os::unix::fs::PermissionsExt};
+
+        let tmp = tempfile::tempdir().unwrap();
+        // reports the library as missing unless its address is passed
+        let bin = tmp.path().join("resolc");
+        fs::write(
+            &bin,
+            r#"#!/bin/sh
+case "$(cat)" in
+*'"libraries":{"src/MyLib.sol":{"MyLib":'*) echo '{"contracts":{"src/Linked.sol":{"Linked":{}}}}' ;;
+*) echo '{"contracts":{"src/Linked.sol":{"Linked":{"missingLibraries":["src/MyLib.sol:MyLib"]}}}}' ;;
+esac
+"#,
+        )
+        .unwrap();
+        fs::set_permissions(&bin, fs::Permissions::from_mode(0o755)).unwrap();
+        let resolc = Resolc {
+            resolc: bin,
+            resolc_version: Version::parse("0.1.0-dev.13").unwrap(),
+            supported_solc_versions: "^0.8.0".parse().unwrap(),
+            solc: SolcCompiler::Specific(Solc::new_with_version("solc", Version::new(0, 8, 28))),
+            locator: resolc::ResolcLocator::empty(),
+        };
+
+        let paths = ProjectPathsConfig::dapptools(tmp.path()).unwrap();
+        fs::create_dir_all(&paths.sources).unwrap();
+        fs::write(paths.sources.join("MyLib.sol"), "pragma solidity ^0.8.0;\nlibrary MyLib {}\n")
+            .unwrap();
+        fs::write(
+            paths.sources.join("Linked.sol"),
+            "pragma solidity ^0.8.0;\nimport \"./MyLib.sol\";\ncontract Linked {}\n",
+        )
+        .unwrap();
+        let project = Project::builder()
+            .paths(paths)
+            .ephemeral()
+            .no_artifacts()
+            .build(MultiCompiler {
+                solidity: multi::SolidityCompiler::Resolc(resolc),
+                ..Default::default()
+            })
+            .unwrap();
+
+        let compiled = project.compile().unwrap();
+        let mut unlinked = compiled.unlinked_polkavm_artifacts();
+        assert_eq!(unlinked.len(), 1);
+        assert_eq!(unlinked[0].0.name, "Linked");
+        assert!(unlinked[0].1.is_missing_fully_qualified("src/MyLib.sol:MyLib"));
+        assert!(project.link_polkavm(&unlinked).is_err());
+
+        unlinked[0].1.link_fully_qualified("src/MyLib.sol:MyLib", Address::ZERO);
+        let linked = project.link_polkavm(&unlinked).unwrap();
+        assert!(linked.find_first("Linked").is_some());
+        assert!(linked.unlinked_polkavm_artifacts().is_empty());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn resolc_standard_json_input_reports_resolved_resolc() {
+        use std::{fs, os::unix::fs::PermissionsExt};
+
+        let tmp = tempfile::tempdir().unwrap();
+        let bin = tmp.path().join("bin");
+        fs::create_dir(&bin).unwrap();
+        let dev16 = bin.join("resolc-0.1.0-dev.16");
+        fs::write(
+            &dev16,
+            r#"#!/bin/sh
+case "$1" in
+--version) echo "Solidity frontend for the revive compiler version 0.1.0-dev.16+commit.d4e5f6a" ;;
+--supported-solc-versions) echo ">=0.8.0, <=0.8.29" ;;
+*) exit 1 ;;
+esac
+"#,
+        )
+        .unwrap();
+        fs::set_permissions(&dev16, fs::Permissions::from_mode(0o755)).unwrap();
+        let resolc = Resolc {
+            resolc: tmp.path().join("resolc"),
+            resolc_version: Version::parse("0.1.0-dev.13").unwrap(),
+            supported_solc_versions: "^0.8.0".parse().unwrap(),
+            solc: SolcCompiler::Specific(Solc::new_with_version("solc", Version::new(0, 8, 28))),
+            locator: resolc::ResolcLocator::empty().dir(&bin),
+        };
+
+        let paths = ProjectPathsConfig::dapptools(tmp.path()).unwrap();
+        fs::create_dir_all(&paths.sources).unwrap();
+        let target = paths.sources.join("A.sol");
+        fs::write(
+            &target,
+            "// pragma resolc >=0.1.0-dev.16;\npragma solidity ^0.8.0;\ncontract A {}\n",
+        )
+        .unwrap();
+        let project = Project::builder()
+            .paths(paths)
+            .ephemeral()
+            .no_artifacts()
+            .offline()
+            .build(MultiCompiler {
+                solidity: multi::SolidityCompiler::Resolc(resolc),
+                ..Default::default()
+            })
+            .unwrap();
+
+        let input = project.resolc_standard_json_input(&target).unwrap();
+        assert_eq!(input.resolc_version, Version::parse("0.1.0-dev.16").unwrap());
+
+        // without a binary satisfying the requirement, no input can be built
+        fs::remove_dir_all(&bin).unwrap();
+        assert!(project.resolc_standard_json_input(&target).is_err());
+    }
+
     #[test]
     fn test_build_many_libs() {
         let root = utils::canonicalize("../../test-data/test-contract-libs").unwrap();
@@ -67,7 +67,7 @@ impl<
             SolidityCompiler::Solc(ref mut s) => {
                 *s = solc;
             }
-            SolidityCompiler::Resolc(resolc) => resolc.solc = solc,
+            SolidityCompiler::Resolc(resolc) | SolidityCompiler::Dual(resolc) => resolc.solc = solc,
             SolidityCompiler::MissingInstallation => {
                 self.inner.compiler.solidity = SolidityCompiler::Solc(solc)
             }
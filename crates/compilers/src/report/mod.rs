@@ -155,6 +155,12 @@ pub trait Reporter: 'static + std::fmt::Debug {
     /// list of all import paths and the file they occurred in: `(import stmt, file)`
     fn on_unresolved_imports(&self, _imports: &[(&Path, &Path)], _remappings: &[Remapping]) {}
 
+    /// Invoked if requested outputs were removed from the input because the compiler can't emit
+    /// them, see [`Compiler::output_capabilities`].
+    ///
+    /// [`Compiler::output_capabilities`]: crate::compilers::Compiler::output_capabilities
+    fn on_unsupported_outputs(&self, _compiler_name: &str, _outputs: &[String]) {}
+
     /// If `self` is the same type as the provided `TypeId`, returns an untyped
     /// [`NonNull`] pointer to that type. Otherwise, returns `None`.
     ///
@@ -225,6 +231,10 @@ pub(crate) fn unresolved_imports(imports: &[(&Path, &Path)], remappings: &[Remap
     get_default(|r| r.reporter.on_unresolved_imports(imports, remappings));
 }
 
+pub(crate) fn unsupported_outputs(compiler_name: &str, outputs: &[String]) {
+    get_default(|r| r.reporter.on_unsupported_outputs(compiler_name, outputs));
+}
+
 fn get_global() -> Option<&'static Report> {
     if GLOBAL_REPORTER_STATE.load(Ordering::SeqCst) != SET {
         return None;
@@ -416,6 +426,13 @@ impl Reporter for BasicStdoutReporter {
         }
         println!("{}", format_unresolved_imports(imports, remappings))
     }
+
+    fn on_unsupported_outputs(&self, compiler_name: &str, outputs: &[String]) {
+        if outputs.is_empty() {
+            return;
+        }
+        println!("{compiler_name} doesn't support the requested outputs: {}", outputs.join(", "));
+    }
 }
 
 /// Creates a meaningful message for all unresolved imports
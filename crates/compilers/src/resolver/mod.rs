@@ -60,7 +60,7 @@ use parse::SolData;
 use rayon::prelude::*;
 use semver::{Version, VersionReq};
 use std::{
-    collections::{BTreeSet, HashMap, HashSet, VecDeque},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
     io,
     path::{Path, PathBuf},
 };
@@ -528,6 +528,9 @@ impl<L: Language, D: ParsedSource<Language = L>> Graph<D> {
 
         let versioned_nodes = self.get_input_node_versions(project)?;
         let versioned_nodes = self.resolve_settings(project, versioned_nodes)?;
+        let resolc_version_reqs = (0..self.edges.num_input_files)
+            .map(|idx| self.resolc_version_req(idx))
+            .collect::<Vec<_>>();
         let (nodes, edges) = self.split();
 
         let mut all_nodes = nodes.into_iter().enumerate().collect::<HashMap<_, _>>();
@@ -543,35 +546,70 @@ impl<L: Language, D: ParsedSource<Language = L>> Graph<D> {
 
             for (version, profile_to_nodes) in versioned_nodes {
                 for (profile_idx, input_node_indixies) in profile_to_nodes {
-                    let mut sources = Sources::new();
-
-                    // all input nodes will be processed
-                    let mut processed_sources = input_node_indixies.iter().copied().collect();
-
-                    // we only process input nodes (from sources, tests for example)
+                    // sources compiled with different `resolc` binaries are compiled separately
+                    let mut resolc_version_groups = BTreeMap::<_, Vec<_>>::new();
+                    let settings = profiles[profile_idx].1;
+                    let mut resolc_versions = HashMap::<_, Option<Version>>::new();
                     for idx in input_node_indixies {
-                        // insert the input node in the sources set and remove it from the available
-                        // set
-                        let (path, source) =
-                            all_nodes.get(&idx).cloned().expect("node is preset. qed");
-
-                        default_profiles.insert(path.clone(), profiles[profile_idx].0);
-                        sources.insert(path, source);
-                        insert_imports(
-                            idx,
-                            &mut all_nodes,
-                            &mut sources,
-                            &edges.edges,
-                            &mut processed_sources,
-                        );
+                        let req = &resolc_version_reqs[idx];
+                        let resolc_version = match resolc_versions.get(req) {
+                            Some(resolc_version) => resolc_version.clone(),
+                            None => {
+                                let resolc_version = project.compiler.resolc_version(
+                                    settings,
+                                    &language,
+                                    &version,
+                                    req.as_ref(),
+                                    project.offline,
+                                )?;
+                                resolc_versions.insert(req, resolc_version.clone());
+                                resolc_version
+                            }
+                        };
+                        resolc_version_groups.entry(resolc_version).or_default().push(idx);
+                    }
+
+                    for input_node_indixies in resolc_version_groups.into_values() {
+                        let mut sources = Sources::new();
+
+                        // all input nodes will be processed
+                        let mut processed_sources = input_node_indixies.iter().copied().collect();
+
+                        // we only process input nodes (from sources, tests for example)
+                        for idx in input_node_indixies {
+                            // insert the input node in the sources set and remove it from the
+                            // available set
+                            let (path, source) =
+                                all_nodes.get(&idx).cloned().expect("node is preset. qed");
+
+                            // a node built with multiple profiles (e.g. for multiple targets)
+                            // keeps the first one as its primary profile
+                            default_profiles
+                                .entry(path.clone())
+                                .and_modify(|idx: &mut usize| *idx = (*idx).min(profile_idx))
+                                .or_insert(profile_idx);
+                            sources.insert(path, source);
+                            insert_imports(
+                                idx,
+                                &mut all_nodes,
+                                &mut sources,
+                                &edges.edges,
+                                &mut processed_sources,
+                            );
+                        }
+                        versioned_sources.push((version.clone(), sources, profiles[profile_idx]));
                     }
-                    versioned_sources.push((version.clone(), sources, profiles[profile_idx]));
                 }
             }
 
             resulted_sources.insert(language, versioned_sources);
         }
 
+        let default_profiles = default_profiles
+            .into_iter()
+            .map(|(path, profile_idx)| (path, profiles[profile_idx].0))
+            .collect();
+
         Ok(ResolvedSources { sources: resulted_sources, primary_profiles: default_profiles, edges })
     }
 
@@ -806,6 +844,18 @@ impl<L: Language, D: ParsedSource<Language = L>> Graph<D> {
         Err(msg)
     }
 
+    /// Returns the combined `resolc` version requirement of the node and all its imports.
+    fn resolc_version_req(&self, idx: usize) -> Option<VersionReq> {
+        let mut comparators = self
+            .node_ids(idx)
+            .filter_map(|idx| self.node(idx).data.resolc_version_req())
+            .flat_map(|req| req.comparators.iter().cloned())
+            .collect::<Vec<_>>();
+        comparators.sort_by_cached_key(ToString::to_string);
+        comparators.dedup();
+        (!comparators.is_empty()).then_some(VersionReq { comparators })
+    }
+
     fn input_nodes_by_language(&self) -> HashMap<D::Language, Vec<usize>> {
         let mut nodes = HashMap::new();
 
@@ -936,6 +986,8 @@ impl<L: Language, D: ParsedSource<Language = L>> Graph<D> {
     ) -> Result<HashMap<L, HashMap<Version, HashMap<usize, Vec<usize>>>>> {
         let mut resulted_sources = HashMap::new();
         let mut errors = Vec::new();
+        // multi-target compilers build every node once per target
+        let targets = project.compiler.targets();
         for (language, versions) in input_nodes_versions {
             let mut versioned_sources = HashMap::new();
             for (version, nodes) in versions {
@@ -947,6 +999,18 @@ impl<L: Language, D: ParsedSource<Language = L>> Graph<D> {
                         self.retain_compatible_profiles(idx, project, &mut profile_candidates)
                     {
                         errors.push(err);
+                    } else if targets.len() > 1 {
+                        for target in &targets {
+                            if let Some((profile_idx, _)) = profile_candidates
+                                .iter()
+                                .find(|(_, (_, settings))| settings.target() == *target)
+                            {
+                                profile_to_nodes
+                                    .entry(*profile_idx)
+                                    .or_insert_with(Vec::new)
+                                    .push(idx);
+                            }
+                        }
                     } else {
                         let (profile_idx, _) = profile_candidates.first().expect("exists");
                         profile_to_nodes.entry(*profile_idx).or_insert_with(Vec::new).push(idx);
@@ -1205,6 +1269,27 @@ mod tests {
         assert_eq!(graph.imported_nodes(1).to_vec(), vec![2, 0]);
     }
 
+    #[test]
+    fn can_combine_resolc_version_reqs() {
+        let tmp = tempfile::tempdir().unwrap();
+        let src = tmp.path().join("src");
+        std::fs::create_dir(&src).unwrap();
+        std::fs::write(src.join("A.sol"), "// pragma resolc >=0.3.0;\nimport \"./B.sol\";")
+            .unwrap();
+        std::fs::write(src.join("B.sol"), "// pragma resolc <0.5.0;\ncontract B {}").unwrap();
+        std::fs::write(src.join("C.sol"), "contract C {}").unwrap();
+        let paths = ProjectPathsConfig::dapptools(tmp.path()).unwrap();
+        let graph = Graph::<SolData>::resolve(&paths).unwrap();
+
+        let req = |file: &str| {
+            let idx = graph.files()[&paths.sources.join(file)];
+            graph.resolc_version_req(idx).map(|req| req.to_string())
+        };
+        assert_eq!(req("A.sol").as_deref(), Some("<0.5.0, >=0.3.0"));
+        assert_eq!(req("B.sol").as_deref(), Some("<0.5.0"));
+        assert_eq!(req("C.sol"), None);
+    }
+
     #[test]
     #[cfg(not(target_os = "windows"))]
     fn can_print_dapp_sample_graph() {
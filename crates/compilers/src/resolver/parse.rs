@@ -15,6 +15,8 @@ pub struct SolData {
     pub experimental: Option<Spanned<String>>,
     pub imports: Vec<Spanned<SolImport>>,
     pub version_req: Option<VersionReq>,
+    /// The `resolc` version requirement, declared via `// pragma resolc <version>;`.
+    pub resolc_version_req: Option<VersionReq>,
     pub libraries: Vec<SolLibrary>,
     pub contract_names: Vec<String>,
     pub is_yul: bool,
@@ -136,9 +138,12 @@ impl SolData {
             .map(|(cap, l)| Spanned::new(l.as_str().to_owned(), cap.range()))
         });
         let version_req = version.as_ref().and_then(|v| Self::parse_version_req(v.data()).ok());
+        let resolc_version_req = utils::find_resolc_version_pragma(content)
+            .and_then(|v| Self::parse_version_req(v.as_str()).ok());
 
         Self {
             version_req,
+            resolc_version_req,
             version,
             experimental,
             imports,
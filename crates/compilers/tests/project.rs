@@ -2,16 +2,19 @@
 
 use alloy_primitives::{Address, Bytes};
 use foundry_compilers::{
-    buildinfo::BuildInfo,
+    buildinfo::{BuildInfo, RawBuildInfo},
     cache::{CompilerCache, SOLIDITY_FILES_CACHE_FILENAME},
     compilers::{
         multi::{
             MultiCompiler, MultiCompilerLanguage, MultiCompilerParsedSource, MultiCompilerSettings,
         },
-        resolc::Resolc,
+        resolc::{
+            PolkaVmLimits, Resolc, ResolcOptimizerMode, ResolcRestrictions, ResolcSettings,
+            ResolcStandardJsonInput,
+        },
         solc::{Solc, SolcCompiler, SolcLanguage},
         vyper::{Vyper, VyperLanguage, VyperSettings},
-        CompilerOutput,
+        CompilationError, CompilationTarget, CompilerOutput, CompilerSettings,
     },
     flatten::Flattener,
     info::ContractInfo,
@@ -24,8 +27,11 @@ use foundry_compilers::{
     TestFileFilter,
 };
 use foundry_compilers_artifacts::{
-    output_selection::OutputSelection, remappings::Remapping, BytecodeHash, Contract, DevDoc,
-    Error, ErrorDoc, EventDoc, EvmVersion, Libraries, MethodDoc, ModelCheckerEngine::CHC,
+    output_selection::OutputSelection,
+    remappings::Remapping,
+    resolc::{is_polkavm_blob, BLOB_SIZE_ERROR_CODE, MISSING_LIBRARIES_ERROR_CODE},
+    BytecodeHash, Contract, DevDoc, Error, ErrorDoc, EventDoc, EvmVersion, Libraries, MethodDoc,
+    ModelCheckerEngine::CHC,
     ModelCheckerSettings, Settings, Severity, SolcInput, UserDoc, UserDocNotice,
 };
 use foundry_compilers_core::{
@@ -208,7 +214,6 @@ fn can_compile_dapp_sample(#[case] compiler: MultiCompiler) {
 
 #[rstest]
 #[case::solc(MultiCompiler::default())]
-#[ignore]
 #[case::resolc(resolc())]
 fn can_compile_yul_sample(#[case] compiler: MultiCompiler) {
     let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../test-data/yul-sample");
@@ -710,6 +715,39 @@ fn can_flatten_file_in_dapp_sample(#[case] compiler: MultiCompiler) {
     });
 }
 
+// Flattening only parses the sources, so the output is pinned without compiling them.
+#[test]
+fn can_flatten_samples_offline() {
+    let test_data = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../test-data");
+    let hardhat = test_data.join("hardhat-sample");
+    let dapp = test_data.join("dapp-sample");
+    for (paths, target, expected) in [
+        (
+            ProjectPathsConfig::builder()
+                .root(&hardhat)
+                .sources(hardhat.join("contracts"))
+                .lib(hardhat.join("node_modules"))
+                .build::<SolcLanguage>()
+                .unwrap(),
+            hardhat.join("contracts/Greeter.sol"),
+            "flattened/Greeter.sol",
+        ),
+        (
+            ProjectPathsConfig::builder()
+                .root(&dapp)
+                .sources(dapp.join("src"))
+                .lib(dapp.join("lib"))
+                .build::<SolcLanguage>()
+                .unwrap(),
+            dapp.join("src/Dapp.t.sol"),
+            "flattened/Dapp.t.sol",
+        ),
+    ] {
+        let flattened = paths.flatten(&canonicalize(target).unwrap()).unwrap();
+        assert_eq!(flattened, fs::read_to_string(test_data.join(expected)).unwrap());
+    }
+}
+
 #[rstest]
 #[case::solc(MultiCompiler::default())]
 #[case::resolc(resolc())]
@@ -4686,3 +4724,500 @@ fn test_output_hash_special_filenames() {
     fs::remove_file(artifacts_path.join("underscore_file.json")).unwrap();
     fs::remove_file(artifacts_path.join("dash-file.json")).unwrap();
 }
+
+#[test]
+fn can_resolve_factory_dependencies() {
+    let mut project = TempProject::<MultiCompiler>::dapptools().unwrap();
+    project.project_mut().compiler = resolc();
+
+    project
+        .add_source(
+            "Grandchild",
+            r"
+pragma solidity ^0.8.10;
+contract Grandchild { }
+",
+        )
+        .unwrap();
+    project
+        .add_source(
+            "Child",
+            r#"
+pragma solidity ^0.8.10;
+import "./Grandchild.sol";
+contract Child {
+    function deploy() external returns (address) {
+        return address(new Grandchild());
+    }
+}
+"#,
+        )
+        .unwrap();
+    project
+        .add_source(
+            "Factory",
+            r#"
+pragma solidity ^0.8.10;
+import "./Child.sol";
+contract Factory {
+    function deploy() external returns (address) {
+        return address(new Child());
+    }
+}
+"#,
+        )
+        .unwrap();
+
+    let compiled = project.compile().unwrap();
+    compiled.assert_success();
+
+    let factory = compiled.find_first("Factory").unwrap();
+    assert_eq!(factory.factory_dependencies.as_ref().unwrap().len(), 1);
+
+    let dependencies = compiled
+        .factory_dependencies(ContractInfo::new("Factory"))
+        .unwrap()
+        .into_iter()
+        .map(|(id, _)| id.name)
+        .collect::<Vec<_>>();
+    assert_eq!(dependencies, ["Grandchild", "Child"]);
+
+    // factory dependencies are persisted in the artifact files
+    let compiled = project.compile().unwrap();
+    assert!(compiled.is_unchanged());
+    assert_eq!(compiled.factory_dependencies(ContractInfo::new("Factory")).unwrap().len(), 2);
+}
+
+#[test]
+fn can_report_missing_libraries() {
+    let mut project = TempProject::<MultiCompiler>::dapptools().unwrap();
+    project.project_mut().compiler = resolc();
+
+    project
+        .add_source(
+            "MyLib",
+            r"
+pragma solidity ^0.8.10;
+library MyLib {
+    function foo() external pure returns (uint256) {
+        return 42;
+    }
+}
+",
+        )
+        .unwrap();
+    project
+        .add_source(
+            "Linked",
+            r#"
+pragma solidity ^0.8.10;
+import "./MyLib.sol";
+contract Linked {
+    function bar() external pure returns (uint256) {
+        return MyLib.foo();
+    }
+}
+"#,
+        )
+        .unwrap();
+
+    let compiled = project.compile().unwrap();
+    assert!(compiled.has_compiler_errors());
+    assert!(compiled
+        .output()
+        .errors
+        .iter()
+        .any(|err| err.error_code() == Some(MISSING_LIBRARIES_ERROR_CODE)));
+
+    let linked = compiled.find_first("Linked").unwrap();
+    let missing_libraries = linked.missing_libraries.as_ref().unwrap();
+    assert_eq!(missing_libraries.len(), 1);
+    assert!(missing_libraries.values().all(|libs| libs.contains("MyLib")));
+
+    project.project_mut().ignored_error_codes.push(MISSING_LIBRARIES_ERROR_CODE);
+    let compiled = project.compile().unwrap();
+    compiled.assert_success();
+
+    // recompile with the library addresses to let resolc embed them
+    let mut unlinked = compiled.unlinked_polkavm_artifacts();
+    assert_eq!(unlinked.len(), 1);
+    let (id, libraries) = &unlinked[0];
+    assert_eq!(id.name, "Linked");
+    assert!(libraries.is_missing_fully_qualified("src/MyLib.sol:MyLib"));
+    assert!(project.project().link_polkavm(&unlinked).is_err());
+    unlinked[0].1.link_fully_qualified("src/MyLib.sol:MyLib", Address::ZERO);
+    project.project_mut().ignored_error_codes.clear();
+
+    let compiled = project.project().link_polkavm(&unlinked).unwrap();
+    compiled.assert_success();
+    assert!(compiled.unlinked_polkavm_artifacts().is_empty());
+    let linked = compiled.find_first("Linked").unwrap();
+    assert!(linked.missing_libraries.as_ref().is_none_or(|libs| libs.is_empty()));
+    assert!(is_polkavm_blob(linked.get_bytecode_bytes().unwrap().as_ref()));
+}
+
+#[test]
+fn resolc_settings_invalidate_resolc_artifacts() {
+    let mut project = TempProject::<MultiCompiler>::dapptools().unwrap();
+    project.project_mut().compiler = resolc();
+    project
+        .add_source(
+            "A",
+            r"
+pragma solidity ^0.8.10;
+contract A { }
+",
+        )
+        .unwrap();
+
+    let compiled = project.compile().unwrap();
+    compiled.assert_success();
+
+    // unset PolkaVM settings are equivalent to the defaults
+    project.project_mut().settings.solc.extra_settings =
+        ResolcSettings::new(None, None, None, Some(false)).unwrap();
+    let compiled = project.compile().unwrap();
+    assert!(compiled.is_unchanged());
+
+    project.project_mut().settings.solc.extra_settings =
+        ResolcSettings::new(None, Some(131072), None, None).unwrap();
+    let compiled = project.compile().unwrap();
+    compiled.assert_success();
+    assert!(!compiled.is_unchanged());
+
+    project.project_mut().settings.solc.extra_settings =
+        ResolcSettings::new(Some('z'), Some(131072), None, None).unwrap();
+    let compiled = project.compile().unwrap();
+    compiled.assert_success();
+    assert!(!compiled.is_unchanged());
+}
+
+#[test]
+fn resolc_settings_keep_solc_artifacts() {
+    let mut project = TempProject::<MultiCompiler>::dapptools().unwrap();
+    project
+        .add_source(
+            "A",
+            r"
+pragma solidity ^0.8.10;
+contract A { }
+",
+        )
+        .unwrap();
+
+    let compiled = project.compile().unwrap();
+    compiled.assert_success();
+
+    project.project_mut().settings.solc.extra_settings =
+        ResolcSettings::new(Some('z'), Some(131072), None, None).unwrap();
+    let compiled = project.compile().unwrap();
+    assert!(compiled.is_unchanged());
+
+    // solc settings still invalidate everything
+    project.project_mut().settings.solc.optimizer.runs = Some(1);
+    let compiled = project.compile().unwrap();
+    compiled.assert_success();
+    assert!(!compiled.is_unchanged());
+}
+
+#[test]
+fn test_resolc_settings_restrictions() {
+    let mut project = TempProject::<MultiCompiler>::dapptools().unwrap();
+    project.project_mut().compiler = resolc();
+
+    let common_path = project.add_source("Common.sol", "").unwrap();
+    let large_path = project
+        .add_source(
+            "Large.sol",
+            r#"
+import "./Common.sol";
+
+contract LargeContract {}
+"#,
+        )
+        .unwrap();
+    let simple_path = project
+        .add_source(
+            "Simple.sol",
+            r#"
+import "./Common.sol";
+
+contract SimpleContract {}
+"#,
+        )
+        .unwrap();
+
+    // Add config with a larger heap
+    let mut large_settings = project.project().settings.clone();
+    large_settings.solc.extra_settings =
+        ResolcSettings::new(Some('z'), Some(256 * 1024), None, None).unwrap();
+    project.project_mut().additional_settings.insert("large".to_string(), large_settings);
+
+    let large_restriction = RestrictionsWithVersion {
+        restrictions: MultiCompilerRestrictions {
+            solc: SolcRestrictions {
+                resolc: ResolcRestrictions {
+                    heap_size: Restriction { min: Some(256 * 1024), ..Default::default() },
+                    optimizer_modes: Some([ResolcOptimizerMode::MinSize].into_iter().collect()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        version: None,
+    };
+    project.project_mut().restrictions.insert(large_path.clone(), large_restriction);
+
+    let output = project.compile().unwrap();
+    output.assert_success();
+
+    let artifacts = output
+        .artifact_ids()
+        .map(|(id, _)| (id.profile, id.source))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        artifacts,
+        vec![
+            ("default".to_string(), common_path.clone()),
+            ("default".to_string(), simple_path),
+            ("large".to_string(), common_path),
+            ("large".to_string(), large_path),
+        ]
+    );
+}
+
+#[test]
+fn can_compile_dual_target() {
+    let mut project = TempProject::<MultiCompiler>::dapptools().unwrap();
+    project.project_mut().compiler =
+        MultiCompiler { solidity: SolidityCompiler::Dual(RESOLC.clone()), ..Default::default() };
+    // the profiles for the PolkaVM target are required
+    let err = project.compile().unwrap_err();
+    assert!(err.to_string().contains("polkavm"), "{err}");
+
+    project.project_mut().set_compiler(MultiCompiler {
+        solidity: SolidityCompiler::Dual(RESOLC.clone()),
+        ..Default::default()
+    });
+    assert!(project.project().missing_target_profiles().is_empty());
+    assert_eq!(
+        project.project().additional_settings["polkavm"].target(),
+        CompilationTarget::PolkaVm
+    );
+
+    project
+        .add_source(
+            "A",
+            r"
+pragma solidity ^0.8.10;
+contract A { }
+",
+        )
+        .unwrap();
+
+    let compiled = project.compile().unwrap();
+    compiled.assert_success();
+
+    let artifacts = compiled
+        .artifacts_with_files()
+        .map(|(_, name, artifact)| {
+            let bytecode = artifact.get_bytecode_bytes().unwrap();
+            (name.clone(), is_polkavm_blob(&bytecode))
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(artifacts.len(), 2);
+    assert!(artifacts.contains(&("A".to_string(), false)));
+    assert!(artifacts.contains(&("A".to_string(), true)));
+
+    let ids = compiled
+        .artifact_ids()
+        .map(|(id, _)| (id.profile, id.path.file_name().unwrap().to_string_lossy().to_string()))
+        .collect::<BTreeSet<_>>();
+    assert_eq!(
+        ids,
+        BTreeSet::from([
+            ("default".to_string(), "A.json".to_string()),
+            ("polkavm".to_string(), "A.polkavm.json".to_string()),
+        ])
+    );
+
+    let compiled = project.compile().unwrap();
+    assert!(compiled.is_unchanged());
+}
+
+#[test]
+fn can_report_polkavm_sizes() {
+    let mut project = TempProject::<MultiCompiler>::dapptools().unwrap();
+    project.project_mut().compiler = resolc();
+    project.project_mut().settings.solc.extra_settings =
+        ResolcSettings::new(None, Some(128 * 1024), None, None).unwrap();
+    project
+        .add_source(
+            "A",
+            r"
+pragma solidity ^0.8.10;
+contract A { }
+",
+        )
+        .unwrap();
+
+    let compiled = project.compile().unwrap();
+    compiled.assert_success();
+
+    let report = compiled.polkavm_size_report(project.project());
+    assert_eq!(report.len(), 1);
+    let (id, size) = &report[0];
+    assert_eq!(id.name, "A");
+    assert!(size.blob_size > 0);
+    assert_eq!(size.heap_size, 128 * 1024);
+    assert_eq!(size.memory_size(), (size.blob_size + 128 * 1024) as u64 + size.stack_size as u64);
+
+    // exceeded limits are reported as diagnostics
+    project.project_mut().settings.solc.extra_settings.limits =
+        PolkaVmLimits { max_blob_size: Some(1), severity: Severity::Error, ..Default::default() };
+    let compiled = project.compile().unwrap();
+    assert!(compiled.has_compiler_errors());
+    assert!(compiled
+        .output()
+        .errors
+        .iter()
+        .any(|err| err.error_code() == Some(BLOB_SIZE_ERROR_CODE)));
+
+    project.project_mut().ignored_error_codes.push(BLOB_SIZE_ERROR_CODE);
+    project.project_mut().settings.solc.extra_settings.limits.max_blob_size = Some(2);
+    project.compile().unwrap().assert_success();
+}
+
+#[test]
+fn can_emit_polkavm_source_maps() {
+    let mut project = TempProject::<MultiCompiler>::dapptools().unwrap();
+    project.project_mut().compiler = resolc();
+    project.project_mut().settings.solc.extra_settings =
+        ResolcSettings::new(None, None, None, Some(true)).unwrap();
+    let content = r"
+pragma solidity ^0.8.10;
+contract A {
+    uint256 x;
+    function set(uint256 value) public {
+        x = value;
+    }
+}
+";
+    project.add_source("A", content).unwrap();
+
+    let compiled = project.compile().unwrap();
+    compiled.assert_success();
+
+    let artifact = compiled.find_first("A").unwrap();
+    let source_map = artifact.polkavm_source_map.as_ref().unwrap();
+    let elements = source_map
+        .iter()
+        .filter(|(_, element)| element.index() == artifact.id)
+        .map(|(_, element)| &content[element.offset() as usize..][..element.length() as usize])
+        .collect::<Vec<_>>();
+    assert!(elements.contains(&"x = value;"), "{elements:?}");
+
+    // no source maps without debug information
+    project.project_mut().settings.solc.extra_settings = ResolcSettings::default();
+    let compiled = project.compile().unwrap();
+    assert!(compiled.find_first("A").unwrap().polkavm_source_map.is_none());
+}
+
+#[test]
+fn can_reproduce_resolc_standard_json_input() {
+    let mut project = TempProject::<MultiCompiler>::dapptools().unwrap();
+    project.project_mut().compiler = resolc();
+    project.project_mut().build_info = true;
+    project.project_mut().settings.solc.extra_settings =
+        ResolcSettings::new(Some('z'), Some(128 * 1024), None, None).unwrap();
+
+    let a = project
+        .add_source(
+            "A",
+            r#"
+pragma solidity ^0.8.10;
+import "./B.sol";
+contract A { }
+"#,
+        )
+        .unwrap();
+    project
+        .add_source(
+            "B",
+            r"
+pragma solidity ^0.8.10;
+contract B { }
+",
+        )
+        .unwrap();
+    project
+        .add_source(
+            "C",
+            r"
+pragma solidity ^0.8.10;
+contract C { }
+",
+        )
+        .unwrap();
+
+    let compiled = project.compile().unwrap();
+    compiled.assert_success();
+
+    let entry = fs::read_dir(project.project().build_info_path()).unwrap().next().unwrap();
+    let info: RawBuildInfo<MultiCompilerLanguage> =
+        utils::read_json_file(&entry.unwrap().path()).unwrap();
+    let info = ResolcStandardJsonInput::from_build_info(&info).unwrap();
+
+    let input = project.project().resolc_standard_json_input(&a).unwrap();
+    assert_eq!(input.resolc_version, RESOLC.resolc_version);
+    assert_eq!(input.solc_version, info.solc_version);
+    assert_eq!(input.input.settings.optimizer.mode, Some(ResolcOptimizerMode::MinSize));
+    // the input contains all sources compiled together with the target
+    assert_eq!(input.input.sources.len(), 3);
+    assert_eq!(
+        serde_json::to_value(&input.input).unwrap(),
+        serde_json::to_value(&info.input).unwrap()
+    );
+}
+
+#[test]
+fn resolc_builds_record_toolchain_versions() {
+    let mut project = TempProject::<MultiCompiler>::dapptools().unwrap();
+    project.project_mut().compiler = resolc();
+    project.project_mut().build_info = true;
+    project
+        .add_source(
+            "A",
+            r"
+pragma solidity ^0.8.10;
+contract A { }
+",
+        )
+        .unwrap();
+
+    let assert_versions = |compiled: &ProjectCompileOutput| {
+        let (_, build) = compiled.builds().next().unwrap();
+        assert_eq!(build.resolc_version.as_ref(), Some(&RESOLC.resolc_version));
+        let solc_long_version = build.solc_long_version.as_ref().unwrap();
+        assert!(!solc_long_version.build.is_empty());
+    };
+
+    let compiled = project.compile().unwrap();
+    compiled.assert_success();
+    assert_versions(&compiled);
+
+    let entry = fs::read_dir(project.project().build_info_path()).unwrap().next().unwrap();
+    let info =
+        BuildInfo::<serde_json::Value, serde_json::Value>::read(&entry.unwrap().path()).unwrap();
+    assert_eq!(info.resolc_version, Some(RESOLC.resolc_version.clone()));
+    assert!(info.solc_long_version.is_some());
+
+    // the versions are read back from the build info
+    let compiled = project.compile().unwrap();
+    assert!(compiled.is_unchanged());
+    assert_versions(&compiled);
+}
@@ -59,10 +59,12 @@ pub enum SolcError {
          --> {1}\n\t\
          {2}"
     )]
-    FailedResolveImport(Box<SolcError>, PathBuf, PathBuf),
+    FailedResolveImport(Box<Self>, PathBuf, PathBuf),
     #[cfg(feature = "svm-solc")]
     #[error(transparent)]
     SvmError(#[from] svm::SvmError),
+    #[error("invalid resolc optimizer mode `{0}`, expected one of `0`, `1`, `2`, `3`, `s` or `z`")]
+    InvalidResolcOptimizerMode(char),
     #[error("no contracts found at \"{0}\"")]
     NoContracts(String),
     /// General purpose message.
@@ -19,6 +19,11 @@ pub static RE_SOL_IMPORT_ALIAS: Lazy<Regex> =
 pub static RE_SOL_PRAGMA_VERSION: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"pragma\s+solidity\s+(?P<version>.+?);").unwrap());
 
+/// A regex that matches the version part of a `resolc` pragma, which is declared in a comment
+/// as follows: `// pragma resolc >=0.3.0;` => `>=0.3.0`
+pub static RE_RESOLC_PRAGMA_VERSION: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"//\s*pragma\s+resolc\s+(?P<version>.+?);").unwrap());
+
 /// A regex that matches the SDPX license identifier
 /// statement with the named group "license".
 pub static RE_SOL_SDPX_LICENSE_IDENTIFIER: Lazy<Regex> =
@@ -63,6 +68,12 @@ pub fn find_version_pragma(contract: &str) -> Option<Match<'_>> {
     RE_SOL_PRAGMA_VERSION.captures(contract)?.name("version")
 }
 
+/// Returns the `resolc` version pragma from the given input:
+/// `// pragma resolc >=0.3.0;` => `>=0.3.0`
+pub fn find_resolc_version_pragma(contract: &str) -> Option<Match<'_>> {
+    RE_RESOLC_PRAGMA_VERSION.captures(contract)?.name("version")
+}
+
 /// Given the regex and the target string, find all occurrences of named groups within the string.
 ///
 /// This method returns the tuple of matches `(a, b)` where `a` is the match for the entire regex
@@ -111,6 +122,16 @@ pragma solidity ^0.8.0;
         assert_eq!(Some("^0.8.0"), find_version_pragma(s).map(|s| s.as_str()));
     }
 
+    #[test]
+    fn can_find_resolc_version() {
+        let s = r"//SPDX-License-Identifier: Unlicense
+pragma solidity ^0.8.0;
+// pragma resolc >=0.3.0 <0.5.0;
+";
+        assert_eq!(Some(">=0.3.0 <0.5.0"), find_resolc_version_pragma(s).map(|s| s.as_str()));
+        assert!(find_resolc_version_pragma("pragma solidity ^0.8.0;").is_none());
+    }
+
     #[test]
     fn can_parse_curly_bracket_imports() {
         let s =